@@ -185,7 +185,7 @@ def extract_job_fields(result: Any) -> tuple[str | None, str | None]:
 def extract_agreement_fields(result: Any) -> tuple[str | None, str | None, str | None]:
     text = json.dumps(result)
     status = None
-    for candidate in ["PendingFunding", "Active", "NoticePeriod", "Terminated", "Completed"]:
+    for candidate in ["PendingFunding", "Active", "NoticePeriod", "Terminated", "Completed", "Cancelled"]:
         if candidate in text:
             status = candidate
             break
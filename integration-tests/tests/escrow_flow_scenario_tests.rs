@@ -0,0 +1,1854 @@
+use job_board_core::JobBoardCore;
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_sc::storage::StorageKey;
+use shared_types::{
+    Agreement, AgreementStatus, AgreementTerms, FundingState, Milestone, MilestoneState,
+    RecurringTermsEscrow, RevenueShareTermsEscrow, TerminationSide,
+};
+use work_escrow::WorkEscrow;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const EMPLOYER: TestAddress = TestAddress::new("employer");
+const WORKER: TestAddress = TestAddress::new("worker");
+const TREASURY: TestAddress = TestAddress::new("treasury");
+
+const BOND_REGISTRY: TestSCAddress = TestSCAddress::new("bond-registry");
+const UPTIME: TestSCAddress = TestSCAddress::new("uptime");
+const BOARD: TestSCAddress = TestSCAddress::new("job-board");
+const ESCROW: TestSCAddress = TestSCAddress::new("work-escrow");
+
+const BOARD_CODE: &str = "job-board-core";
+const ESCROW_CODE: &str = "work-escrow";
+
+const START_TS: u64 = 1_000;
+const PROTOCOL_FEE_BPS: u64 = 1_000;
+const PAY_PER_PERIOD: u64 = 100;
+const PERIOD_SECONDS: u64 = 10;
+const TOTAL_PERIODS: u64 = 10;
+const MILESTONE_AMOUNT: u64 = 500;
+const REVIEW_TIMEOUT_SECONDS: u64 = 50;
+const BOND: u64 = 50;
+const RUNWAY: u64 = 1_000;
+const NOTICE_SECONDS: u64 = 25;
+const PENALTY_BPS: u64 = 1_000;
+const FUNDING_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+const JOB_ID: u64 = 1;
+const APPLICATION_ID: u64 = 1;
+const OFFER_ID: u64 = 1;
+const AGREEMENT_ID: u64 = 1;
+const MILESTONE_ID: u64 = 1;
+
+fn setup() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(format!("str:{BOARD_CODE}"), job_board_core::ContractBuilder);
+    world.register_contract(format!("str:{ESCROW_CODE}"), work_escrow::ContractBuilder);
+
+    world.account(OWNER).nonce(1);
+    world.account(EMPLOYER).nonce(1).balance(10_000u64);
+    world.account(WORKER).nonce(1).balance(10_000u64);
+    world.account(TREASURY).nonce(1);
+    world.current_block().block_timestamp(START_TS);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(ManagedBuffer::<StaticApi>::from(BOARD_CODE))
+        .new_address(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.init(
+                BOND_REGISTRY.to_managed_address(),
+                UPTIME.to_managed_address(),
+                0,
+                5,
+                10,
+            );
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(ManagedBuffer::<StaticApi>::from(ESCROW_CODE))
+        .new_address(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.init(
+                BOARD.to_managed_address(),
+                BOND_REGISTRY.to_managed_address(),
+                UPTIME.to_managed_address(),
+                0,
+                TREASURY.to_managed_address(),
+                PROTOCOL_FEE_BPS,
+                0,
+                BigUint::from(BOND),
+                BigUint::from(BOND),
+                1,
+                20,
+                2_000,
+                REVIEW_TIMEOUT_SECONDS,
+                8,
+                500,
+            );
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.set_escrow(ESCROW.to_managed_address());
+        });
+
+    world
+}
+
+fn offer_terms() -> shared_types::OfferTerms<DebugApi> {
+    shared_types::OfferTerms {
+        payment_token: EgldOrEsdtTokenIdentifier::egld(),
+        recurring: shared_types::RecurringTerms {
+            amount_per_period: BigUint::from(PAY_PER_PERIOD),
+            period_seconds: PERIOD_SECONDS,
+            total_periods: TOTAL_PERIODS,
+        },
+        revenue_share: shared_types::RevenueShareTerms { profit_share_bps: 0 },
+        employer_bond_required: BigUint::from(BOND),
+        worker_bond_required: BigUint::from(BOND),
+        notice_seconds: NOTICE_SECONDS,
+        termination_penalty_bps: PENALTY_BPS,
+        milestones: ManagedVec::new(),
+        terms_uri: ManagedBuffer::from("terms"),
+    }
+}
+
+// The rust VM cannot execute readonly cross-contract calls, which matching and
+// activation rely on, so the state they leave behind is written directly.
+fn seed_accepted_offer(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.job_count().set(JOB_ID);
+            sc.jobs(JOB_ID).set(shared_types::Job {
+                id: JOB_ID,
+                employer: EMPLOYER.to_managed_address(),
+                metadata_uri: ManagedBuffer::from("job"),
+                visibility: shared_types::JobVisibility::Public,
+                application_deadline_ts: START_TS + 10_000,
+                min_worker_uptime: 0,
+                comp_mode_mask: shared_types::COMP_MODE_ALL,
+                status: shared_types::JobStatus::Matched,
+                created_at: START_TS,
+                accepted_offer_id: OFFER_ID,
+                application_count: 1,
+                seats: 1,
+                filled_seats: 1,
+                revision: 0,
+            });
+            sc.offers(JOB_ID, OFFER_ID).set(shared_types::Offer {
+                id: OFFER_ID,
+                job_id: JOB_ID,
+                application_id: APPLICATION_ID,
+                proposer: EMPLOYER.to_managed_address(),
+                counterparty: WORKER.to_managed_address(),
+                party: shared_types::OfferParty::Employer,
+                parent_offer_id: 0,
+                round_index: 0,
+                terms: offer_terms(),
+                status: shared_types::OfferStatus::Accepted,
+                created_at: START_TS,
+                expires_at: 0,
+            });
+            sc.accepted_offers(JOB_ID).insert(OFFER_ID);
+            sc.accepted_offer_by_application(JOB_ID, APPLICATION_ID)
+                .set(OFFER_ID);
+            sc.accepted_offer_timestamp(JOB_ID, OFFER_ID).set(START_TS);
+            sc.matched_job_count().set(1u64);
+        });
+}
+
+// Mirrors the records activateAgreement writes for the accepted offer.
+fn seed_pending_agreement(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            let employer = EMPLOYER.to_managed_address();
+            let worker = WORKER.to_managed_address();
+            sc.agreement_count().set(AGREEMENT_ID);
+            sc.agreements(AGREEMENT_ID).set(Agreement {
+                id: AGREEMENT_ID,
+                job_id: JOB_ID,
+                offer_id: OFFER_ID,
+                employer: employer.clone(),
+                worker: worker.clone(),
+                referrer: ManagedAddress::zero(),
+                status: AgreementStatus::PendingFunding,
+                created_at: START_TS,
+                activated_at: 0,
+                notice_start_ts: 0,
+                notice_end_ts: 0,
+                requested_by_side: 0,
+                default_side: 0,
+                terms: AgreementTerms {
+                    payment_token: EgldOrEsdtTokenIdentifier::egld(),
+                    recurring: RecurringTermsEscrow {
+                        amount_per_period: BigUint::from(PAY_PER_PERIOD),
+                        period_seconds: PERIOD_SECONDS,
+                        total_periods: TOTAL_PERIODS,
+                        paid_periods: 0,
+                        next_pay_ts: 0,
+                    },
+                    revenue_share: RevenueShareTermsEscrow {
+                        profit_share_bps: 0,
+                        protocol_fee_bps_snapshot: PROTOCOL_FEE_BPS,
+                        referral_share_bps_snapshot: 0,
+                    },
+                    employer_bond_required: BigUint::from(BOND),
+                    worker_bond_required: BigUint::from(BOND),
+                    notice_seconds: NOTICE_SECONDS,
+                    termination_penalty_bps: PENALTY_BPS,
+                    milestone_count: 1,
+                },
+                funding_deadline_ts: START_TS + FUNDING_WINDOW_SECONDS,
+            });
+            sc.agreement_financials(AGREEMENT_ID).set(FundingState {
+                runway_balance: BigUint::zero(),
+                employer_bond_locked: BigUint::zero(),
+                worker_bond_locked: BigUint::zero(),
+                reserved_recurring_minimum: BigUint::from(PAY_PER_PERIOD),
+            });
+            sc.offer_consumed(JOB_ID, OFFER_ID).set(true);
+            sc.agreement_by_offer(JOB_ID, OFFER_ID).set(AGREEMENT_ID);
+            sc.milestones(AGREEMENT_ID, MILESTONE_ID).set(Milestone {
+                id: MILESTONE_ID,
+                agreement_id: AGREEMENT_ID,
+                amount: BigUint::from(MILESTONE_AMOUNT),
+                due_ts: 0,
+                review_timeout_seconds: REVIEW_TIMEOUT_SECONDS,
+                metadata_uri: ManagedBuffer::from("milestone"),
+                state: MilestoneState::Open,
+                submitted_at: 0,
+                review_deadline: 0,
+                proof_uri: ManagedBuffer::new(),
+                reason_uri: ManagedBuffer::new(),
+                settlement_mode: 0,
+                paid_at: 0,
+                revision_count: 0,
+            });
+            sc.ensure_reputation_initialized(&employer, AGREEMENT_ID);
+            sc.ensure_reputation_initialized(&worker, AGREEMENT_ID);
+        });
+}
+
+fn activate_funded_agreement(world: &mut ScenarioWorld) {
+    seed_accepted_offer(world);
+    seed_pending_agreement(world);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .egld(BOND + RUNWAY)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.fund_employer_runway(AGREEMENT_ID);
+        });
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .egld(BOND)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.fund_worker_bond(AGREEMENT_ID);
+        });
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let agreement = sc.agreements(AGREEMENT_ID).get();
+        assert!(agreement.status == AgreementStatus::Active);
+        assert_eq!(agreement.terms.recurring.next_pay_ts, START_TS + PERIOD_SECONDS);
+    });
+}
+
+fn set_time(world: &mut ScenarioWorld, ts: u64) {
+    world.current_block().block_timestamp(ts);
+}
+
+fn assert_claimable(world: &mut ScenarioWorld, account: TestAddress, expected: u64) {
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let claimable = sc
+            .claimable(&account.to_managed_address(), &EgldOrEsdtTokenIdentifier::egld())
+            .get();
+        assert_eq!(claimable, BigUint::from(expected));
+    });
+}
+
+fn assert_runway(world: &mut ScenarioWorld, expected: u64) {
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let funding = sc.agreement_financials(AGREEMENT_ID).get();
+        assert_eq!(funding.runway_balance, BigUint::from(expected));
+    });
+}
+
+fn protocol_fee(gross: u64) -> u64 {
+    gross * PROTOCOL_FEE_BPS / 10_000
+}
+
+fn submit_milestone(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.submit_milestone(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("proof"));
+        });
+}
+
+#[test]
+fn employer_termination_settles_notice_pay_and_refunds_runway() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+
+    set_time(&mut world, START_TS + 15);
+    submit_milestone(&mut world);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.request_terminate(AGREEMENT_ID, TerminationSide::Employer);
+        });
+
+    set_time(&mut world, START_TS + 50);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.finalize_terminate(AGREEMENT_ID);
+        });
+
+    // Notice ended at +40: four periods and the submitted milestone are owed to the worker.
+    let worker_gross = 4 * PAY_PER_PERIOD + MILESTONE_AMOUNT;
+    let penalty = BOND * PENALTY_BPS / 10_000;
+
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let agreement = sc.agreements(AGREEMENT_ID).get();
+        assert!(agreement.status == AgreementStatus::Terminated);
+        assert_eq!(agreement.terms.recurring.paid_periods, 4);
+        assert!(sc.milestones(AGREEMENT_ID, MILESTONE_ID).get().state == MilestoneState::Paid);
+    });
+    assert_runway(&mut world, 0);
+    assert_claimable(
+        &mut world,
+        WORKER,
+        worker_gross - protocol_fee(worker_gross) + penalty + BOND,
+    );
+    assert_claimable(&mut world, EMPLOYER, BOND - penalty + RUNWAY - worker_gross);
+    assert_claimable(&mut world, TREASURY, protocol_fee(worker_gross));
+}
+
+#[test]
+fn recurring_claim_catches_up_all_elapsed_periods() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+
+    set_time(&mut world, START_TS + 35);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.claim_recurring_pay(AGREEMENT_ID);
+        });
+
+    let gross = 3 * PAY_PER_PERIOD;
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let recurring = sc.agreements(AGREEMENT_ID).get().terms.recurring;
+        assert_eq!(recurring.paid_periods, 3);
+        assert_eq!(recurring.next_pay_ts, START_TS + 4 * PERIOD_SECONDS);
+    });
+    assert_runway(&mut world, RUNWAY - gross);
+    assert_claimable(&mut world, WORKER, gross - protocol_fee(gross));
+    assert_claimable(&mut world, TREASURY, protocol_fee(gross));
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_INVALID_DEADLINE"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.claim_recurring_pay(AGREEMENT_ID);
+        });
+}
+
+const ARBITRATOR: TestAddress = TestAddress::new("arbitrator");
+
+fn open_dispute(world: &mut ScenarioWorld) {
+    world.account(ARBITRATOR).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.add_arbitrator(ARBITRATOR.to_managed_address());
+        });
+
+    submit_milestone(world);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.reject_milestone(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("reason"));
+        });
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.raise_dispute(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("dispute"));
+        });
+}
+
+fn resolve_dispute(world: &mut ScenarioWorld, worker_share_bps: u64) {
+    world
+        .tx()
+        .from(ARBITRATOR)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.resolve_dispute(AGREEMENT_ID, MILESTONE_ID, worker_share_bps);
+        });
+}
+
+#[test]
+fn arbitrated_dispute_splits_milestone_between_parties() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+    open_dispute(&mut world);
+    resolve_dispute(&mut world, 7_000);
+
+    let worker_gross = MILESTONE_AMOUNT * 7_000 / 10_000;
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let milestone = sc.milestones(AGREEMENT_ID, MILESTONE_ID).get();
+        assert!(milestone.state == MilestoneState::Paid);
+        assert_eq!(sc.disputes(AGREEMENT_ID, MILESTONE_ID).get().worker_share_bps, 7_000);
+    });
+    assert_runway(&mut world, RUNWAY - MILESTONE_AMOUNT);
+    assert_claimable(&mut world, WORKER, worker_gross - protocol_fee(worker_gross));
+    assert_claimable(&mut world, EMPLOYER, MILESTONE_AMOUNT - worker_gross);
+    assert_claimable(&mut world, TREASURY, protocol_fee(worker_gross));
+}
+
+#[test]
+fn unfunded_cancellation_refunds_and_reopens_board_job() {
+    let mut world = setup();
+    seed_accepted_offer(&mut world);
+    seed_pending_agreement(&mut world);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .egld(BOND)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.fund_employer_runway(AGREEMENT_ID);
+        });
+
+    set_time(&mut world, START_TS + FUNDING_WINDOW_SECONDS + 1);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.cancel_unfunded_agreement(AGREEMENT_ID);
+        });
+
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        assert!(sc.agreements(AGREEMENT_ID).get().status == AgreementStatus::Cancelled);
+        assert!(!sc.offer_consumed(JOB_ID, OFFER_ID).get());
+        assert!(sc.offer_released(JOB_ID, OFFER_ID).get());
+    });
+    assert_claimable(&mut world, EMPLOYER, BOND);
+    assert_claimable(&mut world, WORKER, 0);
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let job = sc.jobs(JOB_ID).get();
+        assert!(job.status == shared_types::JobStatus::InNegotiation);
+        assert_eq!(job.filled_seats, 0);
+        assert_eq!(job.accepted_offer_id, 0);
+        assert!(sc.offers(JOB_ID, OFFER_ID).get().status == shared_types::OfferStatus::Void);
+        assert!(sc.accepted_offer_by_application(JOB_ID, APPLICATION_ID).is_empty());
+        assert_eq!(sc.matched_job_count().get(), 0);
+    });
+
+    world
+        .tx()
+        .from(ESCROW)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_STALE_OFFER"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.reopen_matched_job(JOB_ID, OFFER_ID);
+        });
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_OFFER_RELEASED"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.require_offer_unspent(JOB_ID, OFFER_ID);
+        });
+}
+
+#[test]
+fn worker_default_pays_elapsed_periods_before_terminating() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+    let grace_seconds = 5;
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.set_worker_default_grace_seconds(grace_seconds);
+            sc.milestones(AGREEMENT_ID, MILESTONE_ID)
+                .update(|milestone| milestone.due_ts = START_TS + 20);
+        });
+
+    set_time(&mut world, START_TS + 20 + grace_seconds);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_TIMEOUT_NOT_REACHED"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.flag_worker_default(AGREEMENT_ID, MILESTONE_ID);
+        });
+
+    set_time(&mut world, START_TS + 20 + grace_seconds + 1);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.flag_worker_default(AGREEMENT_ID, MILESTONE_ID);
+        });
+
+    let worker_gross = 2 * PAY_PER_PERIOD;
+    let penalty = BOND * PENALTY_BPS / 10_000;
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let agreement = sc.agreements(AGREEMENT_ID).get();
+        assert!(agreement.status == AgreementStatus::Terminated);
+        assert_eq!(agreement.terms.recurring.paid_periods, 2);
+    });
+    assert_runway(&mut world, 0);
+    assert_claimable(
+        &mut world,
+        WORKER,
+        worker_gross - protocol_fee(worker_gross) + BOND - penalty,
+    );
+    assert_claimable(&mut world, EMPLOYER, BOND + penalty + RUNWAY - worker_gross);
+    assert_claimable(&mut world, TREASURY, protocol_fee(worker_gross));
+}
+
+// Must run inside a board whitebox call.
+fn legacy_board_record<T: TopEncode + TopDecode + 'static>(base_key: &str, keys: &[u64], value: T) {
+    let mut key = StorageKey::<DebugApi>::new(base_key.as_bytes());
+    for k in keys {
+        key.append_item(k);
+    }
+    SingleValueMapper::<DebugApi, T>::new(key).set(value);
+}
+
+#[test]
+fn legacy_board_jobs_migrate_with_accepted_offer_index() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let employer = EMPLOYER.to_managed_address::<DebugApi>();
+            let worker = WORKER.to_managed_address::<DebugApi>();
+            // Records as the pre-upgrade board stored them.
+            legacy_board_record(
+                "jobs",
+                &[JOB_ID],
+                (
+                    JOB_ID,
+                    employer.clone(),
+                    ManagedBuffer::<DebugApi>::from("job"),
+                    shared_types::JobVisibility::Public,
+                    START_TS + 10_000,
+                    0u64,
+                    shared_types::COMP_MODE_ALL,
+                    shared_types::JobStatus::Matched,
+                    START_TS,
+                    OFFER_ID,
+                    1u64,
+                ),
+            );
+            legacy_board_record(
+                "applications",
+                &[JOB_ID, APPLICATION_ID],
+                (
+                    APPLICATION_ID,
+                    JOB_ID,
+                    worker.clone(),
+                    ManagedBuffer::<DebugApi>::from("application"),
+                    START_TS,
+                ),
+            );
+            legacy_board_record(
+                "offers",
+                &[JOB_ID, OFFER_ID],
+                (
+                    OFFER_ID,
+                    JOB_ID,
+                    APPLICATION_ID,
+                    employer.clone(),
+                    worker.clone(),
+                    shared_types::OfferParty::Employer,
+                    0u64,
+                    0u64,
+                    (
+                        shared_types::RecurringTerms::<DebugApi> {
+                            amount_per_period: BigUint::from(PAY_PER_PERIOD),
+                            period_seconds: PERIOD_SECONDS,
+                            total_periods: TOTAL_PERIODS,
+                        },
+                        shared_types::RevenueShareTerms { profit_share_bps: 0 },
+                        BigUint::<DebugApi>::from(BOND),
+                        BigUint::<DebugApi>::from(BOND),
+                        ManagedVec::<DebugApi, shared_types::MilestoneSpec<DebugApi>>::new(),
+                        ManagedBuffer::<DebugApi>::from("terms"),
+                    ),
+                    shared_types::OfferStatus::Accepted,
+                    START_TS,
+                ),
+            );
+            legacy_board_record("acceptedOfferId", &[JOB_ID], OFFER_ID);
+            legacy_board_record("acceptedOfferTimestamp", &[JOB_ID], START_TS + 5);
+            sc.job_count().set(JOB_ID);
+            sc.application_count(JOB_ID).set(1u64);
+            sc.offers_by_application(JOB_ID, APPLICATION_ID).push(&OFFER_ID);
+            sc.latest_offer(JOB_ID, APPLICATION_ID).set(OFFER_ID);
+            sc.storage_layout_version().clear();
+            sc.upgrade();
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert!(sc.get_job(JOB_ID).into_option().is_none());
+        assert!(sc.get_accepted_offer_for_application(JOB_ID, APPLICATION_ID).into_option().is_none());
+    });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_MIGRATION_PENDING"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.cancel_job(JOB_ID);
+        });
+
+    // migrateLegacyJobs reads these defaults from the escrow config.
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.migrate_legacy_job(JOB_ID, NOTICE_SECONDS, PENALTY_BPS);
+            sc.legacy_migration_cursor().set(JOB_ID);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let job = sc.get_job(JOB_ID).into_option().unwrap();
+        assert!(job.status == shared_types::JobStatus::Matched);
+        assert_eq!((job.seats, job.filled_seats), (1, 1));
+
+        let application = sc.get_application(JOB_ID, APPLICATION_ID).into_option().unwrap();
+        assert!(application.status == shared_types::ApplicationStatus::Pending);
+        assert_eq!(
+            sc.application_id_by_applicant(JOB_ID, &WORKER.to_managed_address()).get(),
+            APPLICATION_ID
+        );
+
+        let summary = sc
+            .get_accepted_offer_for_application(JOB_ID, APPLICATION_ID)
+            .into_option()
+            .unwrap();
+        assert_eq!(summary.offer_id, OFFER_ID);
+        assert_eq!(summary.accepted_at, START_TS + 5);
+        assert!(summary.terms.payment_token.is_egld());
+        assert_eq!(summary.terms.notice_seconds, NOTICE_SECONDS);
+        assert_eq!(summary.terms.termination_penalty_bps, PENALTY_BPS);
+        assert!(sc.legacy_accepted_offer_id(JOB_ID).is_empty());
+    });
+}
+
+#[test]
+fn termination_holds_disputed_milestone_for_arbitration() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+    open_dispute(&mut world);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.request_terminate(AGREEMENT_ID, TerminationSide::Employer);
+        });
+
+    set_time(&mut world, START_TS + NOTICE_SECONDS);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.finalize_terminate(AGREEMENT_ID);
+        });
+
+    // Notice ended at +25: two and a half periods are owed; the disputed amount is held back.
+    let notice_gross = 2 * PAY_PER_PERIOD + PAY_PER_PERIOD / 2;
+    let penalty = BOND * PENALTY_BPS / 10_000;
+    let employer_after_termination = BOND - penalty + RUNWAY - notice_gross - MILESTONE_AMOUNT;
+    assert_runway(&mut world, MILESTONE_AMOUNT);
+    assert_claimable(&mut world, EMPLOYER, employer_after_termination);
+
+    resolve_dispute(&mut world, 7_000);
+
+    let worker_gross = MILESTONE_AMOUNT * 7_000 / 10_000;
+    let total_gross = notice_gross + worker_gross;
+    assert_runway(&mut world, 0);
+    assert_claimable(
+        &mut world,
+        WORKER,
+        total_gross - protocol_fee(notice_gross) - protocol_fee(worker_gross) + BOND + penalty,
+    );
+    assert_claimable(
+        &mut world,
+        EMPLOYER,
+        employer_after_termination + MILESTONE_AMOUNT - worker_gross,
+    );
+}
+
+#[test]
+fn far_future_milestone_due_date_cannot_trigger_worker_default() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.milestones(AGREEMENT_ID, MILESTONE_ID)
+                .update(|milestone| milestone.due_ts = u64::MAX);
+        });
+
+    set_time(&mut world, START_TS + 20);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_TIMEOUT_NOT_REACHED"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.flag_worker_default(AGREEMENT_ID, MILESTONE_ID);
+        });
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_DEADLINE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.validate_milestone_spec(&shared_types::MilestoneSpec {
+                id: MILESTONE_ID,
+                amount: BigUint::from(MILESTONE_AMOUNT),
+                due_ts: u64::MAX,
+                review_timeout_seconds: REVIEW_TIMEOUT_SECONDS,
+                metadata_uri: ManagedBuffer::from("milestone"),
+            });
+        });
+}
+
+#[test]
+fn negotiated_notice_is_capped_by_owner_maximum() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_INVALID_DEADLINE"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.set_max_notice_seconds(10);
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.set_max_notice_seconds(100);
+        });
+
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let mut terms = offer_terms();
+        terms.notice_seconds = 100;
+        assert_eq!(sc.offer_terms_error(&terms), None);
+        terms.notice_seconds = 101;
+        assert_eq!(sc.offer_terms_error(&terms), Some("ERR_INVALID_DEADLINE"));
+    });
+
+    // Agreements negotiated before the cap still get a well-formed notice end.
+    activate_funded_agreement(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.agreements(AGREEMENT_ID)
+                .update(|agreement| agreement.terms.notice_seconds = u64::MAX);
+        });
+    set_time(&mut world, START_TS + 5);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.request_terminate(AGREEMENT_ID, TerminationSide::Employer);
+            assert_eq!(sc.agreements(AGREEMENT_ID).get().notice_end_ts, u64::MAX);
+        });
+}
+
+#[test]
+fn upgrade_keeps_removed_egld_off_the_allowlist() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.remove_allowed_token(EgldOrEsdtTokenIdentifier::egld());
+            sc.upgrade();
+        });
+
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        assert!(!sc.allowed_tokens().contains(&EgldOrEsdtTokenIdentifier::egld()));
+    });
+}
+
+#[test]
+fn mutual_termination_pays_up_to_agreed_end_date() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+
+    let end_ts = START_TS + 25;
+    let confirm_deadline_ts = START_TS + 100;
+    let settlement = 50u64;
+    set_time(&mut world, START_TS + 5);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.propose_mutual_termination(
+                AGREEMENT_ID,
+                end_ts,
+                confirm_deadline_ts,
+                BigUint::from(settlement),
+            );
+        });
+
+    set_time(&mut world, START_TS + 20);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_TIMEOUT_NOT_REACHED"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.confirm_mutual_termination(
+                AGREEMENT_ID,
+                end_ts,
+                confirm_deadline_ts,
+                BigUint::from(settlement),
+            );
+        });
+
+    set_time(&mut world, START_TS + 30);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_PROPOSAL_MISMATCH"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.confirm_mutual_termination(
+                AGREEMENT_ID,
+                end_ts + 10,
+                confirm_deadline_ts,
+                BigUint::from(settlement),
+            );
+        });
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.confirm_mutual_termination(
+                AGREEMENT_ID,
+                end_ts,
+                confirm_deadline_ts,
+                BigUint::from(settlement),
+            );
+        });
+
+    // Two full periods and half of the third accrued by the end date.
+    let worker_gross = 2 * PAY_PER_PERIOD + PAY_PER_PERIOD / 2 + settlement;
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        let agreement = sc.agreements(AGREEMENT_ID).get();
+        assert!(agreement.status == AgreementStatus::Terminated);
+        assert_eq!(agreement.notice_end_ts, end_ts);
+        assert!(sc.mutual_termination_proposal(AGREEMENT_ID).is_empty());
+    });
+    assert_runway(&mut world, 0);
+    assert_claimable(
+        &mut world,
+        WORKER,
+        worker_gross - protocol_fee(worker_gross) + BOND,
+    );
+    assert_claimable(&mut world, EMPLOYER, BOND + RUNWAY - worker_gross);
+}
+
+fn reject_milestone(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.reject_milestone(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("reason"));
+        });
+}
+
+#[test]
+fn cancelled_milestone_after_revision_limit_completes_agreement() {
+    let mut world = setup();
+    activate_funded_agreement(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.set_max_revision_rounds(1);
+        });
+
+    set_time(&mut world, START_TS + 5);
+    submit_milestone(&mut world);
+    reject_milestone(&mut world);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.resubmit_milestone(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("proof-2"));
+        });
+
+    set_time(&mut world, START_TS + 8);
+    reject_milestone(&mut world);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_REVISION_LIMIT"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.resubmit_milestone(AGREEMENT_ID, MILESTONE_ID, ManagedBuffer::from("proof-3"));
+        });
+
+    // The worker keeps one review window after the final rejection to dispute.
+    set_time(&mut world, START_TS + 8 + REVIEW_TIMEOUT_SECONDS);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .returns(ExpectError(4, "ERR_TIMEOUT_NOT_REACHED"))
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.cancel_milestone(AGREEMENT_ID, MILESTONE_ID);
+        });
+
+    set_time(&mut world, START_TS + 100);
+    world
+        .tx()
+        .from(WORKER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.claim_recurring_pay(AGREEMENT_ID);
+            assert!(sc.agreements(AGREEMENT_ID).get().status == AgreementStatus::Active);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW)
+        .whitebox(work_escrow::contract_obj, |sc| {
+            sc.cancel_milestone(AGREEMENT_ID, MILESTONE_ID);
+        });
+
+    let worker_gross = TOTAL_PERIODS * PAY_PER_PERIOD;
+    world.query().to(ESCROW).whitebox(work_escrow::contract_obj, |sc| {
+        assert!(sc.agreements(AGREEMENT_ID).get().status == AgreementStatus::Completed);
+        let milestone = sc.milestones(AGREEMENT_ID, MILESTONE_ID).get();
+        assert!(milestone.state == MilestoneState::Cancelled);
+        assert_eq!(milestone.revision_count, 1);
+        assert_eq!(sc.milestone_revisions(AGREEMENT_ID, MILESTONE_ID).len(), 2);
+        assert_eq!(sc.completed_agreement_count().get(), 1);
+    });
+    assert_runway(&mut world, 0);
+    assert_claimable(
+        &mut world,
+        WORKER,
+        worker_gross - protocol_fee(worker_gross) + BOND,
+    );
+    assert_claimable(&mut world, EMPLOYER, BOND + RUNWAY - worker_gross);
+}
+
+// Applicant, eligibility and escrow checks need readonly calls, so board tests
+// seed jobs, applications and offers the way createJob, apply and proposeOffer store them.
+fn applicant_address(application_id: u64) -> Address {
+    if application_id == APPLICATION_ID {
+        WORKER.to_address()
+    } else {
+        Address::from([application_id as u8; 32])
+    }
+}
+
+fn seed_board_job(world: &mut ScenarioWorld, job_id: u64, visibility: shared_types::JobVisibility) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.job_count().set(job_id);
+            sc.jobs(job_id).set(shared_types::Job {
+                id: job_id,
+                employer: EMPLOYER.to_managed_address(),
+                metadata_uri: ManagedBuffer::from("job"),
+                visibility,
+                application_deadline_ts: START_TS + 10_000,
+                min_worker_uptime: 0,
+                comp_mode_mask: shared_types::COMP_MODE_ALL,
+                status: shared_types::JobStatus::Open,
+                created_at: START_TS,
+                accepted_offer_id: 0,
+                application_count: 0,
+                seats: 1,
+                filled_seats: 0,
+                revision: 0,
+            });
+            sc.job_index().push(&job_id);
+            sc.job_ids_by_employer(&EMPLOYER.to_managed_address())
+                .push(&job_id);
+            sc.open_job_count().update(|v| *v += 1);
+        });
+}
+
+fn seed_application(world: &mut ScenarioWorld, application_id: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let applicant = ManagedAddress::from(&applicant_address(application_id));
+            sc.applications(JOB_ID, application_id)
+                .set(shared_types::Application {
+                    id: application_id,
+                    job_id: JOB_ID,
+                    applicant: applicant.clone(),
+                    application_uri: ManagedBuffer::from("application"),
+                    created_at: START_TS,
+                    status: shared_types::ApplicationStatus::Pending,
+                    status_reason: ManagedBuffer::new(),
+                });
+            sc.application_count(JOB_ID).set(application_id);
+            sc.has_applied(JOB_ID, &applicant).set(true);
+            sc.applied_job_ids(&applicant).insert(JOB_ID);
+            sc.application_id_by_applicant(JOB_ID, &applicant)
+                .set(application_id);
+            sc.total_application_count().update(|v| *v += 1);
+
+            let mut job = sc.jobs(JOB_ID).get();
+            job.application_count += 1;
+            if job.status == shared_types::JobStatus::Open {
+                job.status = shared_types::JobStatus::InNegotiation;
+                sc.open_job_count().update(|v| *v -= 1);
+            }
+            sc.jobs(JOB_ID).set(job);
+        });
+}
+
+fn seed_pending_offer(world: &mut ScenarioWorld, application_id: u64, offer_id: u64, expires_at: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let offer = shared_types::Offer {
+                id: offer_id,
+                job_id: JOB_ID,
+                application_id,
+                proposer: EMPLOYER.to_managed_address(),
+                counterparty: ManagedAddress::from(&applicant_address(application_id)),
+                party: shared_types::OfferParty::Employer,
+                parent_offer_id: 0,
+                round_index: 0,
+                terms: offer_terms(),
+                status: shared_types::OfferStatus::Proposed,
+                created_at: START_TS,
+                expires_at,
+            };
+            sc.offers(JOB_ID, offer_id).set(&offer);
+            sc.job_offer_seq(JOB_ID).set(offer_id);
+            sc.latest_offer(JOB_ID, application_id).set(offer_id);
+            sc.offers_by_application(JOB_ID, application_id)
+                .push(&offer_id);
+            sc.pending_offers_for(&offer.counterparty)
+                .insert(shared_types::OfferRef {
+                    job_id: JOB_ID,
+                    offer_id,
+                });
+            sc.total_offer_count().update(|v| *v += 1);
+        });
+}
+
+#[test]
+fn withdrawn_application_voids_live_offer_and_reopens_job() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    seed_application(&mut world, APPLICATION_ID);
+    seed_pending_offer(&mut world, APPLICATION_ID, OFFER_ID, 0);
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_UNAUTHORIZED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.withdraw_application(JOB_ID, APPLICATION_ID);
+        });
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.withdraw_application(JOB_ID, APPLICATION_ID);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let worker = WORKER.to_managed_address();
+        let job = sc.jobs(JOB_ID).get();
+        assert!(job.status == shared_types::JobStatus::Open);
+        assert_eq!(job.application_count, 0);
+        assert_eq!(sc.open_job_count().get(), 1);
+        assert!(
+            sc.applications(JOB_ID, APPLICATION_ID).get().status
+                == shared_types::ApplicationStatus::Withdrawn
+        );
+        assert!(!sc.has_applied(JOB_ID, &worker).get());
+        assert!(sc.offers(JOB_ID, OFFER_ID).get().status == shared_types::OfferStatus::Void);
+        assert!(sc.latest_offer(JOB_ID, APPLICATION_ID).is_empty());
+        assert_eq!(sc.pending_offers_for(&worker).len(), 0);
+        assert_eq!(sc.get_applications(JOB_ID, 0, 10, OptionalValue::None).len(), 0);
+    });
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.withdraw_application(JOB_ID, APPLICATION_ID);
+        });
+}
+
+#[test]
+fn expired_offer_cannot_be_accepted_and_frees_the_latest_slot() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    seed_application(&mut world, APPLICATION_ID);
+    let expires_at = START_TS + 10;
+    seed_pending_offer(&mut world, APPLICATION_ID, OFFER_ID, expires_at);
+
+    set_time(&mut world, expires_at);
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_DEADLINE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.expire_offer(JOB_ID, OFFER_ID);
+        });
+
+    set_time(&mut world, expires_at + 1);
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_OFFER_EXPIRED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.accept_offer(JOB_ID, OFFER_ID);
+        });
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let pending = sc.get_pending_offers_for(WORKER.to_managed_address(), 0, 10);
+        assert_eq!(pending.len(), 0);
+    });
+
+    // Anyone may expire the offer once the deadline passed.
+    world
+        .tx()
+        .from(TREASURY)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.expire_offer(JOB_ID, OFFER_ID);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert!(sc.offers(JOB_ID, OFFER_ID).get().status == shared_types::OfferStatus::Expired);
+        assert!(sc.latest_offer(JOB_ID, APPLICATION_ID).is_empty());
+        assert_eq!(sc.pending_offers_for(&WORKER.to_managed_address()).len(), 0);
+        sc.require_no_active_latest_offer(JOB_ID, APPLICATION_ID);
+    });
+}
+
+#[test]
+fn match_supersedes_sibling_offers_in_cursor_batches() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    let applications = 40u64;
+    for application_id in 1..=applications {
+        seed_application(&mut world, application_id);
+        seed_pending_offer(&mut world, application_id, application_id, 0);
+    }
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.accept_offer(JOB_ID, OFFER_ID);
+        });
+
+    // Matching closes the first batch of 32 applications.
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert!(sc.jobs(JOB_ID).get().status == shared_types::JobStatus::Matched);
+        assert!(sc.offers(JOB_ID, OFFER_ID).get().status == shared_types::OfferStatus::Accepted);
+        assert_eq!(sc.sibling_close_cursor(JOB_ID).get(), 32);
+        for offer_id in 2..=32 {
+            assert!(sc.offers(JOB_ID, offer_id).get().status == shared_types::OfferStatus::Superseded);
+        }
+        for offer_id in 33..=applications {
+            assert!(sc.offers(JOB_ID, offer_id).get().status == shared_types::OfferStatus::Proposed);
+        }
+    });
+
+    world
+        .tx()
+        .from(TREASURY)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            assert_eq!(sc.close_sibling_negotiations_endpoint(JOB_ID), 0);
+        });
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert_eq!(sc.sibling_close_cursor(JOB_ID).get(), applications);
+        for offer_id in 33..=applications {
+            let offer = sc.offers(JOB_ID, offer_id).get();
+            assert!(offer.status == shared_types::OfferStatus::Superseded);
+            assert_eq!(sc.pending_offers_for(&offer.counterparty).len(), 0);
+        }
+    });
+
+    world
+        .tx()
+        .from(TREASURY)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.close_sibling_negotiations_endpoint(JOB_ID);
+        });
+}
+
+fn offer_terms_input() -> shared_types::OfferTermsInput<DebugApi> {
+    let terms = offer_terms();
+    shared_types::OfferTermsInput {
+        payment_token: terms.payment_token,
+        expires_at: 0,
+        recurring: terms.recurring,
+        revenue_share: terms.revenue_share,
+        employer_bond_required: terms.employer_bond_required,
+        worker_bond_required: terms.worker_bond_required,
+        notice_seconds: Some(terms.notice_seconds),
+        termination_penalty_bps: Some(terms.termination_penalty_bps),
+        milestones: terms.milestones,
+        terms_uri: terms.terms_uri,
+    }
+}
+
+fn application_ids(
+    applications: MultiValueEncoded<DebugApi, shared_types::Application<DebugApi>>,
+) -> Vec<u64> {
+    applications.into_iter().map(|application| application.id).collect()
+}
+
+#[test]
+fn employer_shortlists_and_rejects_applications() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    for application_id in 1..=3 {
+        seed_application(&mut world, application_id);
+    }
+    seed_pending_offer(&mut world, 2, 1, 0);
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_UNAUTHORIZED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.shortlist_application(JOB_ID, 1, OptionalValue::None);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.shortlist_application(JOB_ID, 1, OptionalValue::Some(ManagedBuffer::from("strong fit")));
+            sc.reject_application(JOB_ID, 2, OptionalValue::Some(ManagedBuffer::from("no fit")));
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let shortlisted = sc.applications(JOB_ID, 1).get();
+        assert!(shortlisted.status == shared_types::ApplicationStatus::Shortlisted);
+        assert_eq!(shortlisted.status_reason, ManagedBuffer::from("strong fit"));
+        assert!(sc.offers(JOB_ID, 1).get().status == shared_types::OfferStatus::Void);
+        assert!(sc.latest_offer(JOB_ID, 2).is_empty());
+
+        let status_page = |status| {
+            application_ids(sc.get_applications(JOB_ID, 0, 10, OptionalValue::Some(status)))
+        };
+        assert_eq!(status_page(shared_types::ApplicationStatus::Shortlisted), vec![1]);
+        assert_eq!(status_page(shared_types::ApplicationStatus::Rejected), vec![2]);
+        assert_eq!(status_page(shared_types::ApplicationStatus::Pending), vec![3]);
+        assert_eq!(
+            application_ids(sc.get_applications(JOB_ID, 0, 10, OptionalValue::None)),
+            vec![1, 2, 3]
+        );
+    });
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_APPLICATION_REJECTED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.propose_offer(JOB_ID, 2, offer_terms_input());
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.shortlist_application(JOB_ID, 2, OptionalValue::None);
+        });
+}
+
+#[test]
+fn employer_updates_open_job_within_bounds() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    let deadline = START_TS + 10_000;
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_UNAUTHORIZED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.update_job(JOB_ID, ManagedBuffer::from("job-v2"), deadline, 0);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_DEADLINE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.update_job(JOB_ID, ManagedBuffer::from("job-v2"), deadline - 1, 0);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.update_job(JOB_ID, ManagedBuffer::from("job-v2"), deadline + 100, 80);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let job = sc.jobs(JOB_ID).get();
+        assert_eq!(job.metadata_uri, ManagedBuffer::from("job-v2"));
+        assert_eq!(job.application_deadline_ts, deadline + 100);
+        assert_eq!(job.min_worker_uptime, 80);
+        assert_eq!(job.revision, 1);
+    });
+
+    // Once someone applied, the uptime bar may only drop.
+    seed_application(&mut world, APPLICATION_ID);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.update_job(JOB_ID, ManagedBuffer::from("job-v3"), deadline + 100, 90);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.update_job(JOB_ID, ManagedBuffer::from("job-v3"), deadline + 100, 50);
+            let job = sc.jobs(JOB_ID).get();
+            assert!(job.status == shared_types::JobStatus::InNegotiation);
+            assert_eq!(job.min_worker_uptime, 50);
+            assert_eq!(job.revision, 2);
+        });
+}
+
+fn applicant_addresses(application_ids: &[u64]) -> MultiValueEncoded<DebugApi, ManagedAddress<DebugApi>> {
+    let mut out = MultiValueEncoded::new();
+    for application_id in application_ids {
+        out.push(ManagedAddress::from(&applicant_address(*application_id)));
+    }
+    out
+}
+
+#[test]
+fn employer_manages_invites_until_first_application() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::InviteOnly);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.set_max_invites_per_job(3);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.add_invites(JOB_ID, applicant_addresses(&[1, 2]));
+            sc.add_invites(JOB_ID, applicant_addresses(&[2, 3]));
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_AMOUNT"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.add_invites(JOB_ID, applicant_addresses(&[4]));
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.remove_invites(JOB_ID, applicant_addresses(&[2]));
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert_eq!(sc.get_invites(JOB_ID, 0, 10).len(), 2);
+        assert_eq!(sc.get_invites(JOB_ID, 1, 10).len(), 1);
+        assert!(sc.is_invite_allowed(JOB_ID, WORKER.to_managed_address()));
+        assert!(!sc.is_invite_allowed(JOB_ID, ManagedAddress::from(&applicant_address(2))));
+    });
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.set_job_visibility(JOB_ID, shared_types::JobVisibility::Public);
+        });
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert!(sc.jobs(JOB_ID).get().visibility == shared_types::JobVisibility::Public);
+        assert_eq!(sc.invites(JOB_ID).len(), 0);
+        assert!(sc.is_invite_allowed(JOB_ID, ManagedAddress::from(&applicant_address(2))));
+    });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.add_invites(JOB_ID, applicant_addresses(&[1]));
+        });
+
+    // Visibility is fixed once an application arrived.
+    seed_application(&mut world, APPLICATION_ID);
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.set_job_visibility(JOB_ID, shared_types::JobVisibility::InviteOnly);
+        });
+}
+
+fn job_ids(jobs: MultiValueEncoded<DebugApi, shared_types::Job<DebugApi>>) -> Vec<u64> {
+    jobs.into_iter().map(|job| job.id).collect()
+}
+
+#[test]
+fn job_discovery_views_page_over_the_job_index() {
+    let mut world = setup();
+    for job_id in 1..=4 {
+        seed_board_job(&mut world, job_id, shared_types::JobVisibility::Public);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.jobs(2).update(|job| job.status = shared_types::JobStatus::Matched);
+            sc.jobs(3).update(|job| job.application_deadline_ts = START_TS);
+        });
+
+    set_time(&mut world, START_TS + 1);
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert_eq!(job_ids(sc.get_jobs(0, 10, OptionalValue::None)), vec![1, 2, 3, 4]);
+        assert_eq!(job_ids(sc.get_jobs(1, 2, OptionalValue::None)), vec![2, 3]);
+        assert_eq!(
+            job_ids(sc.get_jobs(0, 10, OptionalValue::Some(shared_types::JobStatus::Matched))),
+            vec![2]
+        );
+        assert_eq!(job_ids(sc.get_open_jobs(0, 10, OptionalValue::None)), vec![1, 4]);
+        assert_eq!(
+            job_ids(sc.get_jobs_by_employer(EMPLOYER.to_managed_address(), 2, 10)),
+            vec![3, 4]
+        );
+        assert_eq!(sc.get_jobs_by_employer(WORKER.to_managed_address(), 0, 10).len(), 0);
+        assert_eq!(sc.get_jobs(0, 0, OptionalValue::None).len(), 0);
+    });
+}
+
+#[test]
+fn agent_inbox_views_track_applications_and_pending_offers() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    seed_application(&mut world, APPLICATION_ID);
+    seed_application(&mut world, 2);
+    seed_pending_offer(&mut world, APPLICATION_ID, OFFER_ID, 0);
+    seed_pending_offer(&mut world, 2, 2, START_TS + 10);
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let applications = sc.get_applications_by_applicant(WORKER.to_managed_address(), 0, 10);
+        assert_eq!(application_ids(applications), vec![APPLICATION_ID]);
+        let pending = sc.get_pending_offers_for(WORKER.to_managed_address(), 0, 10);
+        assert_eq!(pending.len(), 1);
+        let pending = sc.get_pending_offers_for(ManagedAddress::from(&applicant_address(2)), 0, 10);
+        assert_eq!(pending.len(), 1);
+    });
+
+    // Expired offers drop out of the inbox before anyone calls expireOffer.
+    set_time(&mut world, START_TS + 11);
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.reject_offer(JOB_ID, OFFER_ID);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert_eq!(sc.get_pending_offers_for(WORKER.to_managed_address(), 0, 10).len(), 0);
+        assert_eq!(sc.pending_offers_for(&WORKER.to_managed_address()).len(), 0);
+        let applicant = ManagedAddress::from(&applicant_address(2));
+        assert_eq!(sc.get_pending_offers_for(applicant.clone(), 0, 10).len(), 0);
+        assert_eq!(sc.pending_offers_for(&applicant).len(), 1);
+    });
+}
+
+fn seed_job_requirements(world: &mut ScenarioWorld, job_id: u64, skill_tag_ids: &[u64]) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let mut tags = ManagedVec::new();
+            for tag_id in skill_tag_ids {
+                tags.push(*tag_id);
+                sc.jobs_by_tag(*tag_id).push(&job_id);
+            }
+            sc.job_requirements(job_id).set(shared_types::JobRequirements {
+                skill_tag_ids: tags,
+                budget_min: BigUint::zero(),
+                budget_max: BigUint::from(RUNWAY),
+                expected_duration_seconds: TOTAL_PERIODS * PERIOD_SECONDS,
+                min_reputation_score: 0,
+                max_worker_defaults: None,
+            });
+        });
+}
+
+#[test]
+fn jobs_are_listed_under_their_registered_skill_tags() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            assert_eq!(sc.register_skill_tag(ManagedBuffer::from("rust")), 1);
+            assert_eq!(sc.register_skill_tag(ManagedBuffer::from("audit")), 2);
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.register_skill_tag(ManagedBuffer::from("rust"));
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_UNKNOWN_SKILL_TAG"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let mut tags = ManagedVec::new();
+            tags.push(3u64);
+            sc.validate_job_requirements(&shared_types::JobRequirements {
+                skill_tag_ids: tags,
+                budget_min: BigUint::zero(),
+                budget_max: BigUint::zero(),
+                expected_duration_seconds: 0,
+                min_reputation_score: 0,
+                max_worker_defaults: None,
+            });
+        });
+
+    for job_id in 1..=3 {
+        seed_board_job(&mut world, job_id, shared_types::JobVisibility::Public);
+    }
+    seed_job_requirements(&mut world, 1, &[1]);
+    seed_job_requirements(&mut world, 2, &[1, 2]);
+    seed_job_requirements(&mut world, 3, &[2]);
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        assert_eq!(job_ids(sc.get_jobs_by_tag(1, 0, 10)), vec![1, 2]);
+        assert_eq!(job_ids(sc.get_jobs_by_tag(2, 0, 10)), vec![2, 3]);
+        assert_eq!(job_ids(sc.get_jobs_by_tag(2, 1, 10)), vec![3]);
+        assert_eq!(sc.get_jobs_by_tag(3, 0, 10).len(), 0);
+        assert_eq!(sc.get_skill_tags(0, 10).len(), 2);
+    });
+}
+
+#[test]
+fn multi_seat_job_matches_once_every_seat_is_filled() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.jobs(JOB_ID).update(|job| job.seats = 2);
+        });
+    for application_id in 1..=3 {
+        seed_application(&mut world, application_id);
+        seed_pending_offer(&mut world, application_id, application_id, 0);
+    }
+    let second_worker = applicant_address(2);
+    world.account(&second_worker).nonce(1);
+
+    world
+        .tx()
+        .from(WORKER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.accept_offer(JOB_ID, 1);
+            let job = sc.jobs(JOB_ID).get();
+            assert!(job.status == shared_types::JobStatus::InNegotiation);
+            assert_eq!(job.filled_seats, 1);
+            assert!(sc.offers(JOB_ID, 3).get().status == shared_types::OfferStatus::Proposed);
+        });
+    world
+        .tx()
+        .from(&second_worker)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.accept_offer(JOB_ID, 2);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let job = sc.jobs(JOB_ID).get();
+        assert!(job.status == shared_types::JobStatus::Matched);
+        assert_eq!(job.filled_seats, 2);
+        assert_eq!(sc.accepted_offers(JOB_ID).len(), 2);
+        assert_eq!(sc.matched_job_count().get(), 1);
+        assert!(sc.offers(JOB_ID, 3).get().status == shared_types::OfferStatus::Superseded);
+    });
+
+    // Releasing one seat reopens the job while the other match stands.
+    world
+        .tx()
+        .from(ESCROW)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.reopen_matched_job(JOB_ID, 1);
+        });
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let job = sc.jobs(JOB_ID).get();
+        assert!(job.status == shared_types::JobStatus::InNegotiation);
+        assert_eq!(job.filled_seats, 1);
+        assert_eq!(job.accepted_offer_id, 2);
+        assert_eq!(sc.matched_job_count().get(), 0);
+    });
+}
+
+#[test]
+fn offers_are_limited_to_the_job_comp_modes() {
+    let mut world = setup();
+    seed_board_job(&mut world, JOB_ID, shared_types::JobVisibility::Public);
+    seed_application(&mut world, APPLICATION_ID);
+    // Without an escrow the board skips the readonly terms check the rust VM cannot run.
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.escrow().clear();
+            sc.jobs(JOB_ID)
+                .update(|job| job.comp_mode_mask = shared_types::COMP_MODE_RECURRING);
+        });
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_COMP_MODE_NOT_ALLOWED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let mut terms = offer_terms_input();
+            terms.revenue_share.profit_share_bps = 500;
+            sc.propose_offer(JOB_ID, APPLICATION_ID, terms);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_COMP_MODE_NOT_ALLOWED"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            let mut terms = offer_terms_input();
+            terms.milestones.push(shared_types::MilestoneSpec {
+                id: MILESTONE_ID,
+                amount: BigUint::from(MILESTONE_AMOUNT),
+                due_ts: 0,
+                review_timeout_seconds: REVIEW_TIMEOUT_SECONDS,
+                metadata_uri: ManagedBuffer::from("milestone"),
+            });
+            sc.propose_offer(JOB_ID, APPLICATION_ID, terms);
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            assert_eq!(sc.propose_offer(JOB_ID, APPLICATION_ID, offer_terms_input()), 1);
+            assert!(sc.offers(JOB_ID, 1).get().status == shared_types::OfferStatus::Proposed);
+        });
+}
+
+#[test]
+fn open_jobs_view_applies_the_requirements_filter() {
+    let mut world = setup();
+    for job_id in 1..=3 {
+        seed_board_job(&mut world, job_id, shared_types::JobVisibility::Public);
+    }
+    seed_job_requirements(&mut world, 1, &[1]);
+    seed_job_requirements(&mut world, 2, &[2]);
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.job_requirements(2)
+                .update(|requirements| requirements.min_reputation_score = 400);
+        });
+
+    world.query().to(BOARD).whitebox(job_board_core::contract_obj, |sc| {
+        let open_jobs = |skill_tag_id, min_budget: u64, max_duration_seconds, reputation_score| {
+            job_ids(sc.get_open_jobs(
+                0,
+                10,
+                OptionalValue::Some(shared_types::JobRequirementsFilter {
+                    skill_tag_id,
+                    min_budget: BigUint::from(min_budget),
+                    max_duration_seconds,
+                    reputation_score,
+                }),
+            ))
+        };
+        assert_eq!(open_jobs(0, 0, 0, 0), vec![1, 3]);
+        assert_eq!(open_jobs(0, 0, 0, 400), vec![1, 2, 3]);
+        assert_eq!(open_jobs(1, 0, 0, 400), vec![1]);
+        assert_eq!(open_jobs(0, RUNWAY, 0, 400), vec![1, 2]);
+        assert_eq!(open_jobs(0, RUNWAY + 1, 0, 400), Vec::<u64>::new());
+        assert_eq!(open_jobs(0, 0, TOTAL_PERIODS * PERIOD_SECONDS - 1, 400), Vec::<u64>::new());
+    });
+}
+
+#[test]
+fn reputation_requirements_need_an_escrow_and_a_valid_score() {
+    let mut world = setup();
+    let requirements = |min_reputation_score, max_worker_defaults| shared_types::JobRequirements {
+        skill_tag_ids: ManagedVec::new(),
+        budget_min: BigUint::zero(),
+        budget_max: BigUint::zero(),
+        expected_duration_seconds: 0,
+        min_reputation_score,
+        max_worker_defaults,
+    };
+
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_AMOUNT"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.validate_job_requirements(&requirements(shared_types::SCORE_MAX + 1, None));
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.validate_job_requirements(&requirements(shared_types::SCORE_MAX, Some(0)));
+        });
+
+    // Admission reads reputation from the escrow, so it cannot be required without one.
+    world
+        .tx()
+        .from(OWNER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.escrow().clear();
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .returns(ExpectError(4, "ERR_INVALID_STATE"))
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.validate_job_requirements(&requirements(0, Some(0)));
+        });
+    world
+        .tx()
+        .from(EMPLOYER)
+        .to(BOARD)
+        .whitebox(job_board_core::contract_obj, |sc| {
+            sc.validate_job_requirements(&requirements(0, None));
+        });
+}
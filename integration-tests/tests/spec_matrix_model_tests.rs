@@ -142,3 +142,43 @@ fn edge_boundaries_model() {
     assert_eq!(max_milestones, 32);
     assert_eq!(max_counteroffers, 8);
 }
+
+#[test]
+fn runway_refund_excludes_outstanding_model() {
+    let runway_balance = 1_000u128;
+    let accrued_periods = 2u128;
+    let amount_per_period = 150u128;
+    let submitted_milestones = 200u128;
+    let outstanding = accrued_periods * amount_per_period + submitted_milestones;
+    let refund = runway_balance.saturating_sub(outstanding);
+    assert_eq!(refund, 500);
+    assert_eq!(refund + outstanding, runway_balance);
+}
+
+#[test]
+fn recurring_catch_up_model() {
+    let next_pay_ts = 1_000u64;
+    let period_seconds = 100u64;
+    let now = 1_450u64;
+    let remaining_periods = 10u64;
+    let due = ((now - next_pay_ts) / period_seconds + 1).min(remaining_periods);
+    assert_eq!(due, 5);
+
+    let runway_balance = 350u128;
+    let amount_per_period = 100u128;
+    let affordable = (runway_balance / amount_per_period) as u64;
+    let paid = due.min(affordable);
+    assert_eq!(paid, 3);
+    assert!(paid < due);
+}
+
+#[test]
+fn notice_pro_rata_model() {
+    let amount_per_period = 1_000u128;
+    let period_seconds = 3_600u64;
+    let next_pay_ts = 10_800u64;
+    let notice_end_ts = 9_000u64;
+    let period_start = next_pay_ts - period_seconds;
+    let accrued = amount_per_period * (notice_end_ts - period_start) as u128 / period_seconds as u128;
+    assert_eq!(accrued, 500);
+}
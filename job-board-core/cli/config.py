@@ -8,17 +8,31 @@ EXPLORER_BASE = os.getenv("EXPLORER_BASE", "https://explorer.claws.network")
 
 DEFAULT_GAS_LIMITS = {
     "createJob": 30000000,
+    "updateJob": 20000000,
+    "addInvites": 20000000,
+    "removeInvites": 20000000,
+    "setJobVisibility": 10000000,
+    "setAgentSkillTags": 15000000,
     "apply": 15000000,
+    "withdrawApplication": 12000000,
+    "shortlistApplication": 12000000,
+    "rejectApplication": 15000000,
     "proposeOffer": 30000000,
     "counterOffer": 25000000,
     "rejectOffer": 12000000,
     "withdrawOffer": 12000000,
-    "acceptOffer": 15000000,
+    "acceptOffer": 50000000,
+    "closeSiblingNegotiations": 60000000,
+    "expireOffer": 12000000,
     "cancelJob": 12000000,
     "expireJob": 12000000,
+    "reopenMatchedJob": 20000000,
+    "setEscrow": 10000000,
     "setMinUptimeScore": 10000000,
     "setMaxCounteroffersPerApplication": 10000000,
     "setMaxInvitesPerJob": 10000000,
     "setPaused": 10000000,
     "setOwner": 10000000,
+    "registerSkillTag": 10000000,
+    "migrateLegacyJobs": 100000000,
 }
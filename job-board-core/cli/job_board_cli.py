@@ -16,19 +16,33 @@ from config import (
 
 MUTABLE_ENDPOINTS = [
     "createJob",
+    "updateJob",
+    "addInvites",
+    "removeInvites",
+    "setJobVisibility",
+    "setAgentSkillTags",
     "apply",
+    "withdrawApplication",
+    "shortlistApplication",
+    "rejectApplication",
     "proposeOffer",
     "counterOffer",
     "rejectOffer",
     "withdrawOffer",
     "acceptOffer",
+    "closeSiblingNegotiations",
+    "expireOffer",
     "cancelJob",
     "expireJob",
+    "reopenMatchedJob",
+    "setEscrow",
     "setMinUptimeScore",
     "setMaxCounteroffersPerApplication",
     "setMaxInvitesPerJob",
     "setPaused",
     "setOwner",
+    "registerSkillTag",
+    "migrateLegacyJobs",
 ]
 
 
@@ -0,0 +1,59 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared_types::{
+    JobStatus, JobVisibility, MilestoneSpec, OfferParty, OfferStatus, RecurringTerms,
+    RevenueShareTerms,
+};
+
+// Storage layouts written before multi-seat jobs, application statuses, offer
+// expiry, payment tokens and negotiated termination terms were added.
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyJob<M: ManagedTypeApi> {
+    pub id: u64,
+    pub employer: ManagedAddress<M>,
+    pub metadata_uri: ManagedBuffer<M>,
+    pub visibility: JobVisibility,
+    pub application_deadline_ts: u64,
+    pub min_worker_uptime: u64,
+    pub comp_mode_mask: u8,
+    pub status: JobStatus,
+    pub created_at: u64,
+    pub accepted_offer_id: u64,
+    pub application_count: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyApplication<M: ManagedTypeApi> {
+    pub id: u64,
+    pub job_id: u64,
+    pub applicant: ManagedAddress<M>,
+    pub application_uri: ManagedBuffer<M>,
+    pub created_at: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyOfferTerms<M: ManagedTypeApi> {
+    pub recurring: RecurringTerms<M>,
+    pub revenue_share: RevenueShareTerms,
+    pub employer_bond_required: BigUint<M>,
+    pub worker_bond_required: BigUint<M>,
+    pub milestones: ManagedVec<M, MilestoneSpec<M>>,
+    pub terms_uri: ManagedBuffer<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyOffer<M: ManagedTypeApi> {
+    pub id: u64,
+    pub job_id: u64,
+    pub application_id: u64,
+    pub proposer: ManagedAddress<M>,
+    pub counterparty: ManagedAddress<M>,
+    pub party: OfferParty,
+    pub parent_offer_id: u64,
+    pub round_index: u64,
+    pub terms: LegacyOfferTerms<M>,
+    pub status: OfferStatus,
+    pub created_at: u64,
+}
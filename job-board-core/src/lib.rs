@@ -4,16 +4,23 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 mod bond_registry_proxy;
+mod legacy_types;
 mod uptime_proxy;
+mod work_escrow_proxy;
 
 use bond_registry_proxy::BondRegistryProxy;
+use legacy_types::{LegacyApplication, LegacyJob, LegacyOffer};
 use shared_types::{
-    AcceptedOfferSummary, Application, BoardStats, Job, JobBoardConfig, JobCloseReason, JobStatus,
-    JobVisibility, MilestoneSpec, Offer, OfferParty, OfferStatus, OfferTerms, OfferTermsInput,
-    BPS_DENOMINATOR, MAX_APPLICATION_URI_LEN, MAX_METADATA_URI_LEN, MAX_PAGE_SIZE,
-    MAX_TERMS_URI_LEN,
+    AcceptedOfferSummary, Application, ApplicationStatus, BoardStats, Job, JobBoardConfig,
+    JobCloseReason, JobRequirements, JobRequirementsFilter, JobStatus, JobVisibility,
+    MilestoneSpec, Offer, OfferParty, OfferRef, OfferStatus, OfferTerms, OfferTermsInput,
+    BPS_DENOMINATOR, COMP_MODE_ALL, COMP_MODE_MILESTONE, COMP_MODE_RECURRING,
+    COMP_MODE_REVENUE_SHARE, MAX_APPLICATION_URI_LEN, MAX_METADATA_URI_LEN, MAX_PAGE_SIZE,
+    MAX_REASON_URI_LEN, MAX_SEATS_PER_JOB, MAX_SKILL_TAGS_PER_AGENT, MAX_SKILL_TAGS_PER_JOB,
+    MAX_SKILL_TAG_NAME_LEN, MAX_TERMS_URI_LEN, SCORE_MAX,
 };
 use uptime_proxy::UptimeProxy;
+use work_escrow_proxy::WorkEscrowProxy;
 
 pub const ERR_PAUSED: &str = "ERR_PAUSED";
 pub const ERR_UNAUTHORIZED: &str = "ERR_UNAUTHORIZED";
@@ -28,9 +35,21 @@ pub const ERR_ALREADY_APPLIED: &str = "ERR_ALREADY_APPLIED";
 pub const ERR_COUNTER_LIMIT: &str = "ERR_COUNTER_LIMIT";
 pub const ERR_STALE_OFFER: &str = "ERR_STALE_OFFER";
 pub const ERR_ALREADY_MATCHED: &str = "ERR_ALREADY_MATCHED";
+pub const ERR_INVALID_TOKEN: &str = "ERR_INVALID_TOKEN";
+pub const ERR_COMP_MODE_NOT_ALLOWED: &str = "ERR_COMP_MODE_NOT_ALLOWED";
+pub const ERR_OFFER_EXPIRED: &str = "ERR_OFFER_EXPIRED";
+pub const ERR_APPLICATION_REJECTED: &str = "ERR_APPLICATION_REJECTED";
+pub const ERR_UNKNOWN_SKILL_TAG: &str = "ERR_UNKNOWN_SKILL_TAG";
+pub const ERR_LOW_REPUTATION: &str = "ERR_LOW_REPUTATION";
+pub const ERR_TOO_MANY_DEFAULTS: &str = "ERR_TOO_MANY_DEFAULTS";
+pub const ERR_TERMS_NOT_ACTIVATABLE: &str = "ERR_TERMS_NOT_ACTIVATABLE";
+pub const ERR_MIGRATION_PENDING: &str = "ERR_MIGRATION_PENDING";
 
 const MAX_INVITE_LOOP_GUARD: usize = 1024;
 const MAX_MILESTONES_PER_OFFER: usize = 32;
+const MAX_MILESTONE_DUE_HORIZON_SECONDS: u64 = 5 * 365 * 24 * 60 * 60;
+const MAX_SIBLING_CLOSE_BATCH: u64 = 32;
+const STORAGE_LAYOUT_VERSION: u64 = 1;
 
 #[multiversx_sc::contract]
 pub trait JobBoardCore {
@@ -63,10 +82,17 @@ pub trait JobBoardCore {
         self.total_offer_count().set(0u64);
         self.open_job_count().set(0u64);
         self.matched_job_count().set(0u64);
+        self.storage_layout_version().set(STORAGE_LAYOUT_VERSION);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        // Jobs stored before the current layout are rewritten by migrateLegacyJobs.
+        if self.storage_layout_version().get() < STORAGE_LAYOUT_VERSION {
+            self.legacy_job_watermark().set(self.job_count().get());
+            self.storage_layout_version().set(STORAGE_LAYOUT_VERSION);
+        }
+    }
 
     #[endpoint(createJob)]
     fn create_job(
@@ -76,6 +102,8 @@ pub trait JobBoardCore {
         application_deadline_ts: u64,
         min_worker_uptime: u64,
         comp_mode_mask: u8,
+        seats: u64,
+        requirements: Option<JobRequirements<Self::Api>>,
         invited: MultiValueEncoded<ManagedAddress>,
     ) -> u64 {
         self.require_not_paused();
@@ -103,7 +131,14 @@ pub trait JobBoardCore {
             require!(invites_vec.is_empty(), ERR_INVALID_STATE);
         }
 
-        require!(comp_mode_mask > 0 && comp_mode_mask <= 0b111, ERR_INVALID_STATE);
+        require!(
+            comp_mode_mask > 0 && comp_mode_mask <= COMP_MODE_ALL,
+            ERR_INVALID_STATE
+        );
+        require!(seats > 0 && seats <= MAX_SEATS_PER_JOB, ERR_INVALID_AMOUNT);
+        if let Some(requirements) = &requirements {
+            self.validate_job_requirements(requirements);
+        }
 
         let job_id = self.job_count().get() + 1;
         self.job_count().set(job_id);
@@ -121,6 +156,9 @@ pub trait JobBoardCore {
             created_at: now,
             accepted_offer_id: 0,
             application_count: 0,
+            seats,
+            filled_seats: 0,
+            revision: 0,
         };
 
         self.jobs(job_id).set(job);
@@ -134,10 +172,133 @@ pub trait JobBoardCore {
             }
         }
 
-        self.job_created_event(job_id, &caller, visibility, now);
+        if let Some(requirements) = &requirements {
+            self.job_requirements(job_id).set(requirements);
+            for tag_id in requirements.skill_tag_ids.iter() {
+                self.jobs_by_tag(tag_id).push(&job_id);
+            }
+        }
+
+        self.job_created_event(job_id, &caller, visibility, &requirements, now);
         job_id
     }
 
+    #[endpoint(updateJob)]
+    fn update_job(
+        &self,
+        job_id: u64,
+        metadata_uri: ManagedBuffer,
+        application_deadline_ts: u64,
+        min_worker_uptime: u64,
+    ) {
+        self.require_not_paused();
+        let mut job = self.require_job(job_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == job.employer, ERR_UNAUTHORIZED);
+        require!(
+            job.status == JobStatus::Open || job.status == JobStatus::InNegotiation,
+            ERR_INVALID_STATE
+        );
+
+        require!(!metadata_uri.is_empty(), ERR_INVALID_AMOUNT);
+        require!(metadata_uri.len() <= MAX_METADATA_URI_LEN, ERR_INVALID_AMOUNT);
+        require!(
+            application_deadline_ts >= job.application_deadline_ts
+                && application_deadline_ts > self.blockchain().get_block_timestamp(),
+            ERR_INVALID_DEADLINE
+        );
+        // Applicants already admitted must keep qualifying, so the bar can only drop.
+        if self.application_count(job_id).get() > 0 {
+            require!(min_worker_uptime <= job.min_worker_uptime, ERR_INVALID_STATE);
+        }
+
+        job.metadata_uri = metadata_uri;
+        job.application_deadline_ts = application_deadline_ts;
+        job.min_worker_uptime = min_worker_uptime;
+        job.revision += 1;
+        let revision = job.revision;
+        self.jobs(job_id).set(job);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.job_updated_event(job_id, &caller, revision, ts);
+    }
+
+    #[endpoint(addInvites)]
+    fn add_invites(&self, job_id: u64, invited: MultiValueEncoded<ManagedAddress>) {
+        self.require_not_paused();
+        let job = self.require_open_job_for_employer(job_id);
+        require!(job.visibility == JobVisibility::InviteOnly, ERR_INVALID_STATE);
+        require!(invited.len() <= MAX_INVITE_LOOP_GUARD, ERR_INVALID_AMOUNT);
+
+        let mut added = 0u64;
+        for addr in invited.into_iter() {
+            if self.invites(job_id).insert(addr) {
+                added += 1;
+            }
+        }
+        require!(
+            self.invites(job_id).len() as u64 <= self.max_invites_per_job().get(),
+            ERR_INVALID_AMOUNT
+        );
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.invites_added_event(job_id, &job.employer, added, ts);
+    }
+
+    #[endpoint(removeInvites)]
+    fn remove_invites(&self, job_id: u64, invited: MultiValueEncoded<ManagedAddress>) {
+        self.require_not_paused();
+        let job = self.require_open_job_for_employer(job_id);
+        require!(job.visibility == JobVisibility::InviteOnly, ERR_INVALID_STATE);
+        require!(invited.len() <= MAX_INVITE_LOOP_GUARD, ERR_INVALID_AMOUNT);
+
+        let mut removed = 0u64;
+        for addr in invited.into_iter() {
+            if self.invites(job_id).swap_remove(&addr) {
+                removed += 1;
+            }
+        }
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.invites_removed_event(job_id, &job.employer, removed, ts);
+    }
+
+    #[endpoint(setJobVisibility)]
+    fn set_job_visibility(&self, job_id: u64, visibility: JobVisibility) {
+        self.require_not_paused();
+        let mut job = self.require_open_job_for_employer(job_id);
+        require!(self.application_count(job_id).get() == 0, ERR_INVALID_STATE);
+        require!(job.visibility != visibility, ERR_INVALID_STATE);
+
+        if visibility == JobVisibility::Public {
+            self.invites(job_id).clear();
+        }
+        job.visibility = visibility;
+        let employer = job.employer.clone();
+        self.jobs(job_id).set(job);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.job_visibility_changed_event(job_id, &employer, visibility, ts);
+    }
+
+    #[endpoint(setAgentSkillTags)]
+    fn set_agent_skill_tags(&self, tag_ids: MultiValueEncoded<u64>) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        self.require_eligible_agent(&caller, self.min_uptime_score().get());
+        require!(tag_ids.len() <= MAX_SKILL_TAGS_PER_AGENT, ERR_INVALID_AMOUNT);
+
+        self.agent_skill_tags(&caller).clear();
+        for tag_id in tag_ids.into_iter() {
+            self.require_skill_tag(tag_id);
+            self.agent_skill_tags(&caller).insert(tag_id);
+        }
+
+        let count = self.agent_skill_tags(&caller).len() as u64;
+        let ts = self.blockchain().get_block_timestamp();
+        self.agent_skill_tags_updated_event(&caller, count, ts);
+    }
+
     #[endpoint(apply)]
     fn apply(&self, job_id: u64, application_uri: ManagedBuffer) -> u64 {
         self.require_not_paused();
@@ -162,6 +323,7 @@ pub trait JobBoardCore {
             self.min_uptime_score().get()
         };
         self.require_eligible_agent(&caller, required_uptime);
+        self.require_reputation_admission(job_id, &caller);
 
         if job.visibility == JobVisibility::InviteOnly {
             require!(self.invites(job_id).contains(&caller), ERR_NOT_INVITED);
@@ -180,8 +342,13 @@ pub trait JobBoardCore {
             applicant: caller.clone(),
             application_uri,
             created_at: now,
+            status: ApplicationStatus::Pending,
+            status_reason: ManagedBuffer::new(),
         };
         self.applications(job_id, application_id).set(application);
+        self.applied_job_ids(&caller).insert(job_id);
+        self.application_id_by_applicant(job_id, &caller)
+            .set(application_id);
 
         job.application_count += 1;
         if job.status == JobStatus::Open {
@@ -200,6 +367,115 @@ pub trait JobBoardCore {
         application_id
     }
 
+    #[endpoint(withdrawApplication)]
+    fn withdraw_application(&self, job_id: u64, application_id: u64) {
+        self.require_not_paused();
+        let mut job = self.require_job(job_id);
+        require!(
+            job.status != JobStatus::Closed && job.status != JobStatus::Expired,
+            ERR_INVALID_STATE
+        );
+
+        let mut application = self.require_application(job_id, application_id);
+        require!(
+            application.status == ApplicationStatus::Pending
+                || application.status == ApplicationStatus::Shortlisted,
+            ERR_INVALID_STATE
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == application.applicant, ERR_UNAUTHORIZED);
+        require!(
+            self.accepted_offer_by_application(job_id, application_id).is_empty(),
+            ERR_ALREADY_MATCHED
+        );
+
+        let voided_offer_id = self.void_live_offer(job_id, application_id);
+
+        application.status = ApplicationStatus::Withdrawn;
+        self.applications(job_id, application_id).set(application);
+        self.has_applied(job_id, &caller).clear();
+
+        if job.application_count > 0 {
+            job.application_count -= 1;
+        }
+        if job.application_count == 0 && job.status == JobStatus::InNegotiation {
+            job.status = JobStatus::Open;
+            self.open_job_count().update(|v| *v += 1);
+        }
+        self.jobs(job_id).set(job);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.application_withdrawn_event(job_id, application_id, &caller, voided_offer_id, ts);
+    }
+
+    #[endpoint(shortlistApplication)]
+    fn shortlist_application(
+        &self,
+        job_id: u64,
+        application_id: u64,
+        reason: OptionalValue<ManagedBuffer>,
+    ) {
+        self.require_not_paused();
+        let job = self.require_job(job_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == job.employer, ERR_UNAUTHORIZED);
+        require!(
+            job.status != JobStatus::Closed && job.status != JobStatus::Expired,
+            ERR_INVALID_STATE
+        );
+
+        let mut application = self.require_application(job_id, application_id);
+        require!(
+            application.status == ApplicationStatus::Pending,
+            ERR_INVALID_STATE
+        );
+
+        application.status = ApplicationStatus::Shortlisted;
+        application.status_reason = self.resolve_status_reason(reason);
+        self.applications(job_id, application_id).set(application);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.application_shortlisted_event(job_id, application_id, &caller, ts);
+    }
+
+    #[endpoint(rejectApplication)]
+    fn reject_application(
+        &self,
+        job_id: u64,
+        application_id: u64,
+        reason: OptionalValue<ManagedBuffer>,
+    ) {
+        self.require_not_paused();
+        let job = self.require_job(job_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == job.employer, ERR_UNAUTHORIZED);
+        require!(
+            job.status != JobStatus::Closed && job.status != JobStatus::Expired,
+            ERR_INVALID_STATE
+        );
+
+        let mut application = self.require_application(job_id, application_id);
+        require!(
+            application.status == ApplicationStatus::Pending
+                || application.status == ApplicationStatus::Shortlisted,
+            ERR_INVALID_STATE
+        );
+        require!(
+            self.accepted_offer_by_application(job_id, application_id).is_empty(),
+            ERR_ALREADY_MATCHED
+        );
+
+        let voided_offer_id = self.void_live_offer(job_id, application_id);
+
+        application.status = ApplicationStatus::Rejected;
+        application.status_reason = self.resolve_status_reason(reason);
+        self.applications(job_id, application_id).set(application);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.application_rejected_event(job_id, application_id, &caller, voided_offer_id, ts);
+    }
+
     #[endpoint(proposeOffer)]
     fn propose_offer(&self, job_id: u64, application_id: u64, terms: OfferTermsInput<Self::Api>) -> u64 {
         self.require_not_paused();
@@ -212,6 +488,14 @@ pub trait JobBoardCore {
                 && job.status != JobStatus::Expired,
             ERR_INVALID_STATE
         );
+        require!(
+            application.status != ApplicationStatus::Withdrawn,
+            ERR_INVALID_STATE
+        );
+        require!(
+            application.status != ApplicationStatus::Rejected,
+            ERR_APPLICATION_REJECTED
+        );
 
         let caller = self.blockchain().get_caller();
         require!(
@@ -219,7 +503,12 @@ pub trait JobBoardCore {
             ERR_UNAUTHORIZED
         );
 
-        self.validate_offer_terms(&terms);
+        require!(
+            self.accepted_offer_by_application(job_id, application_id).is_empty(),
+            ERR_ALREADY_MATCHED
+        );
+        self.require_reputation_admission(job_id, &application.applicant);
+        self.validate_offer_terms(&job, &terms);
         self.require_no_active_latest_offer(job_id, application_id);
 
         let offer_id = self.next_offer_id(job_id);
@@ -243,11 +532,13 @@ pub trait JobBoardCore {
             party,
             parent_offer_id: 0,
             round_index: 0,
+            expires_at: terms.expires_at,
             terms: self.terms_input_to_terms(terms),
             status: OfferStatus::Proposed,
             created_at: self.blockchain().get_block_timestamp(),
         };
 
+        self.track_pending_offer(&offer);
         self.offers(job_id, offer_id).set(offer);
         self.offer_count(job_id, application_id).update(|v| *v += 1);
         self.offers_by_application(job_id, application_id).push(&offer_id);
@@ -267,7 +558,8 @@ pub trait JobBoardCore {
         terms: OfferTermsInput<Self::Api>,
     ) -> u64 {
         self.require_not_paused();
-        self.validate_offer_terms(&terms);
+        let job = self.require_job(job_id);
+        self.validate_offer_terms(&job, &terms);
 
         let mut prev_offer = self.require_offer(job_id, offer_id);
         require!(
@@ -277,9 +569,12 @@ pub trait JobBoardCore {
 
         let caller = self.blockchain().get_caller();
         require!(caller == prev_offer.counterparty, ERR_UNAUTHORIZED);
+        self.require_offer_not_expired(&prev_offer);
 
         let latest = self.latest_offer(job_id, prev_offer.application_id).get();
         require!(latest == offer_id, ERR_STALE_OFFER);
+        let applicant = self.application_applicant(job_id, prev_offer.application_id);
+        self.require_reputation_admission(job_id, &applicant);
 
         let counter_count = self.counter_count(job_id, prev_offer.application_id).get();
         require!(
@@ -289,6 +584,7 @@ pub trait JobBoardCore {
 
         prev_offer.status = OfferStatus::Countered;
         self.offers(job_id, offer_id).set(prev_offer.clone());
+        self.untrack_pending_offer(&prev_offer);
 
         let new_offer_id = self.next_offer_id(job_id);
         let party = if caller == prev_offer.proposer {
@@ -311,11 +607,13 @@ pub trait JobBoardCore {
             party,
             parent_offer_id: offer_id,
             round_index: prev_offer.round_index + 1,
+            expires_at: terms.expires_at,
             terms: self.terms_input_to_terms(terms),
             status: OfferStatus::Proposed,
             created_at: self.blockchain().get_block_timestamp(),
         };
 
+        self.track_pending_offer(&new_offer);
         self.offers(job_id, new_offer_id).set(new_offer);
         self.offer_count(job_id, prev_offer.application_id)
             .update(|v| *v += 1);
@@ -356,6 +654,7 @@ pub trait JobBoardCore {
         require!(caller == offer.counterparty, ERR_UNAUTHORIZED);
 
         offer.status = OfferStatus::Rejected;
+        self.untrack_pending_offer(&offer);
         self.offers(job_id, offer_id).set(offer);
 
         let ts = self.blockchain().get_block_timestamp();
@@ -378,6 +677,7 @@ pub trait JobBoardCore {
         require!(caller == offer.proposer, ERR_UNAUTHORIZED);
 
         offer.status = OfferStatus::Withdrawn;
+        self.untrack_pending_offer(&offer);
         self.offers(job_id, offer_id).set(offer);
 
         let ts = self.blockchain().get_block_timestamp();
@@ -401,21 +701,73 @@ pub trait JobBoardCore {
 
         let caller = self.blockchain().get_caller();
         require!(caller == offer.counterparty, ERR_UNAUTHORIZED);
+        self.require_offer_not_expired(&offer);
+        // Reputation can drop while the offer is pending, so admission is re-checked here.
+        let applicant = self.application_applicant(job_id, offer.application_id);
+        self.require_reputation_admission(job_id, &applicant);
 
         offer.status = OfferStatus::Accepted;
-        self.offers(job_id, offer_id).set(offer);
+        self.offers(job_id, offer_id).set(&offer);
+        self.untrack_pending_offer(&offer);
 
-        self.accepted_offer_id(job_id).set(offer_id);
-        self.accepted_offer_timestamp(job_id)
-            .set(self.blockchain().get_block_timestamp());
+        let ts = self.blockchain().get_block_timestamp();
+        self.accepted_offers(job_id).insert(offer_id);
+        self.accepted_offer_by_application(job_id, offer.application_id)
+            .set(offer_id);
+        self.accepted_offer_timestamp(job_id, offer_id).set(ts);
 
-        job.status = JobStatus::Matched;
         job.accepted_offer_id = offer_id;
+        job.filled_seats += 1;
+        let all_seats_filled = job.filled_seats >= job.seats;
+        if all_seats_filled {
+            job.status = JobStatus::Matched;
+            self.matched_job_count().update(|v| *v += 1);
+        }
         self.jobs(job_id).set(job);
-        self.matched_job_count().update(|v| *v += 1);
 
-        let ts = self.blockchain().get_block_timestamp();
         self.offer_accepted_event(job_id, offer_id, &caller, ts);
+        if all_seats_filled {
+            self.sibling_close_cursor(job_id).clear();
+            self.close_sibling_negotiations(job_id);
+        }
+    }
+
+    #[endpoint(closeSiblingNegotiations)]
+    fn close_sibling_negotiations_endpoint(&self, job_id: u64) -> u64 {
+        self.require_not_paused();
+        let job = self.require_job(job_id);
+        require!(job.status == JobStatus::Matched, ERR_INVALID_STATE);
+        require!(
+            self.sibling_close_cursor(job_id).get() < self.application_count(job_id).get(),
+            ERR_INVALID_STATE
+        );
+        self.close_sibling_negotiations(job_id)
+    }
+
+    #[endpoint(expireOffer)]
+    fn expire_offer(&self, job_id: u64, offer_id: u64) {
+        self.require_not_paused();
+        let mut offer = self.require_offer(job_id, offer_id);
+        require!(
+            offer.status == OfferStatus::Proposed || offer.status == OfferStatus::Countered,
+            ERR_INVALID_STATE
+        );
+        require!(
+            offer.expires_at > 0 && self.blockchain().get_block_timestamp() > offer.expires_at,
+            ERR_INVALID_DEADLINE
+        );
+
+        let latest = self.latest_offer(job_id, offer.application_id).get();
+        require!(latest == offer_id, ERR_STALE_OFFER);
+
+        offer.status = OfferStatus::Expired;
+        self.offers(job_id, offer_id).set(&offer);
+        self.untrack_pending_offer(&offer);
+        self.latest_offer(job_id, offer.application_id).clear();
+
+        let caller = self.blockchain().get_caller();
+        let ts = self.blockchain().get_block_timestamp();
+        self.offer_expired_event(job_id, offer_id, &caller, ts);
     }
 
     #[endpoint(cancelJob)]
@@ -425,10 +777,7 @@ pub trait JobBoardCore {
         let caller = self.blockchain().get_caller();
         require!(caller == job.employer, ERR_UNAUTHORIZED);
 
-        require!(
-            !(job.status == JobStatus::Matched && job.accepted_offer_id != 0),
-            ERR_ALREADY_MATCHED
-        );
+        require!(job.filled_seats == 0, ERR_ALREADY_MATCHED);
         require!(
             job.status != JobStatus::Closed && job.status != JobStatus::Expired,
             ERR_INVALID_STATE
@@ -485,6 +834,62 @@ pub trait JobBoardCore {
         self.job_closed_event(job_id, &caller, JobCloseReason::Expired, ts);
     }
 
+    #[endpoint(reopenMatchedJob)]
+    fn reopen_matched_job(&self, job_id: u64, offer_id: u64) {
+        require!(!self.escrow().is_empty(), ERR_UNAUTHORIZED);
+        // The escrow pushes this best-effort; anyone may retry once it has released the offer.
+        let escrow = self.escrow().get();
+        if self.blockchain().get_caller() != escrow {
+            let released = self
+                .tx()
+                .to(&escrow)
+                .typed(WorkEscrowProxy)
+                .offer_released(job_id, offer_id)
+                .returns(ReturnsResult)
+                .sync_call_readonly();
+            require!(released, ERR_UNAUTHORIZED);
+        }
+
+        let mut job = self.require_job(job_id);
+        require!(self.accepted_offers(job_id).contains(&offer_id), ERR_STALE_OFFER);
+
+        let mut offer = self.require_offer(job_id, offer_id);
+        require!(offer.status == OfferStatus::Accepted, ERR_INVALID_STATE);
+        offer.status = OfferStatus::Void;
+        self.offers(job_id, offer_id).set(&offer);
+
+        self.accepted_offers(job_id).swap_remove(&offer_id);
+        self.accepted_offer_by_application(job_id, offer.application_id)
+            .clear();
+        self.accepted_offer_timestamp(job_id, offer_id).clear();
+
+        if job.accepted_offer_id == offer_id {
+            job.accepted_offer_id = self.accepted_offers(job_id).iter().next().unwrap_or(0);
+        }
+        if job.filled_seats > 0 {
+            job.filled_seats -= 1;
+        }
+        if job.status == JobStatus::Matched {
+            job.status = JobStatus::InNegotiation;
+            self.matched_job_count().update(|v| {
+                if *v > 0 {
+                    *v -= 1;
+                }
+            });
+        }
+        self.jobs(job_id).set(job);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.job_reopened_event(job_id, offer_id, ts);
+    }
+
+    #[endpoint(setEscrow)]
+    fn set_escrow(&self, escrow: ManagedAddress) {
+        self.require_owner();
+        require!(!escrow.is_zero(), ERR_INVALID_AMOUNT);
+        self.escrow().set(escrow);
+    }
+
     #[endpoint(setMinUptimeScore)]
     fn set_min_uptime_score(&self, value: u64) {
         self.require_owner();
@@ -518,41 +923,179 @@ pub trait JobBoardCore {
         self.owner().set(new_owner);
     }
 
+    #[endpoint(registerSkillTag)]
+    fn register_skill_tag(&self, name: ManagedBuffer) -> u64 {
+        self.require_owner();
+        require!(!name.is_empty(), ERR_INVALID_AMOUNT);
+        require!(name.len() <= MAX_SKILL_TAG_NAME_LEN, ERR_INVALID_AMOUNT);
+        require!(self.skill_tag_id_by_name(&name).is_empty(), ERR_INVALID_STATE);
+
+        let tag_id = self.skill_tag_count().get() + 1;
+        self.skill_tag_count().set(tag_id);
+        self.skill_tag_name(tag_id).set(&name);
+        self.skill_tag_id_by_name(&name).set(tag_id);
+
+        let ts = self.blockchain().get_block_timestamp();
+        self.skill_tag_registered_event(tag_id, &name, ts);
+        tag_id
+    }
+
+    #[endpoint(migrateLegacyJobs)]
+    fn migrate_legacy_jobs(&self, max_count: u64) -> u64 {
+        self.require_owner();
+        require!(max_count > 0, ERR_INVALID_AMOUNT);
+        let watermark = self.legacy_job_watermark().get();
+        let from = self.legacy_migration_cursor().get() + 1;
+        let to = core::cmp::min(watermark, from + max_count - 1);
+        require!(from <= to, ERR_INVALID_STATE);
+
+        // Legacy offers always took the escrow's termination defaults at activation.
+        require!(!self.escrow().is_empty(), ERR_INVALID_STATE);
+        let config = self
+            .tx()
+            .to(self.escrow().get())
+            .typed(WorkEscrowProxy)
+            .get_config()
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        for job_id in from..=to {
+            self.migrate_legacy_job(
+                job_id,
+                config.default_notice_seconds,
+                config.termination_penalty_bps,
+            );
+        }
+        self.legacy_migration_cursor().set(to);
+
+        self.legacy_jobs_migrated_event(from, to, self.blockchain().get_block_timestamp());
+        watermark - to
+    }
+
     #[view(getJob)]
     fn get_job(&self, job_id: u64) -> OptionalValue<Job<Self::Api>> {
-        if self.jobs(job_id).is_empty() {
+        if self.jobs(job_id).is_empty() || self.is_legacy_job(job_id) {
             OptionalValue::None
         } else {
             OptionalValue::Some(self.jobs(job_id).get())
         }
     }
 
-    #[view(getApplication)]
-    fn get_application(&self, job_id: u64, application_id: u64) -> OptionalValue<Application<Self::Api>> {
-        if self.applications(job_id, application_id).is_empty() {
-            OptionalValue::None
-        } else {
-            OptionalValue::Some(self.applications(job_id, application_id).get())
-        }
+    #[view(getJobs)]
+    fn get_jobs(
+        &self,
+        from: u64,
+        size: u64,
+        status_filter: OptionalValue<JobStatus>,
+    ) -> MultiValueEncoded<Job<Self::Api>> {
+        let status_filter = status_filter.into_option();
+        self.collect_job_page(self.job_index(), from, size, |job| match status_filter {
+            Some(status) => job.status == status,
+            None => true,
+        })
     }
 
-    #[view(getOffer)]
-    fn get_offer(&self, job_id: u64, offer_id: u64) -> OptionalValue<Offer<Self::Api>> {
-        if self.offers(job_id, offer_id).is_empty() {
-            OptionalValue::None
-        } else {
-            OptionalValue::Some(self.offers(job_id, offer_id).get())
-        }
+    #[view(getJobsByEmployer)]
+    fn get_jobs_by_employer(
+        &self,
+        employer: ManagedAddress,
+        from: u64,
+        size: u64,
+    ) -> MultiValueEncoded<Job<Self::Api>> {
+        self.collect_job_page(self.job_ids_by_employer(&employer), from, size, |_| true)
     }
 
-    #[view(getApplications)]
-    fn get_applications(&self, job_id: u64, from: u64, size: u64) -> MultiValueEncoded<Application<Self::Api>> {
-        let mut out = MultiValueEncoded::new();
-        let count = self.application_count(job_id).get();
-        if count == 0 {
-            return out;
-        }
-
+    #[view(getOpenJobs)]
+    fn get_open_jobs(
+        &self,
+        from: u64,
+        size: u64,
+        requirements_filter: OptionalValue<JobRequirementsFilter<Self::Api>>,
+    ) -> MultiValueEncoded<Job<Self::Api>> {
+        let requirements_filter = requirements_filter.into_option();
+        let now = self.blockchain().get_block_timestamp();
+        self.collect_job_page(self.job_index(), from, size, |job| {
+            (job.status == JobStatus::Open || job.status == JobStatus::InNegotiation)
+                && now <= job.application_deadline_ts
+                && match &requirements_filter {
+                    Some(filter) => self.job_matches_requirements_filter(job.id, filter),
+                    None => true,
+                }
+        })
+    }
+
+    #[view(getJobsByTag)]
+    fn get_jobs_by_tag(&self, tag_id: u64, from: u64, size: u64) -> MultiValueEncoded<Job<Self::Api>> {
+        self.collect_job_page(self.jobs_by_tag(tag_id), from, size, |_| true)
+    }
+
+    #[view(getSkillTags)]
+    fn get_skill_tags(&self, from: u64, size: u64) -> MultiValueEncoded<MultiValue2<u64, ManagedBuffer>> {
+        let mut out = MultiValueEncoded::new();
+        let total = self.skill_tag_count().get();
+        let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
+
+        let mut emitted = 0;
+        let mut tag_id = from + 1;
+        while tag_id <= total && emitted < effective_size {
+            out.push((tag_id, self.skill_tag_name(tag_id).get()).into());
+            tag_id += 1;
+            emitted += 1;
+        }
+        out
+    }
+
+    #[view(getAgentSkillTags)]
+    fn get_agent_skill_tags(&self, agent: ManagedAddress) -> MultiValueEncoded<u64> {
+        let mut out = MultiValueEncoded::new();
+        for tag_id in self.agent_skill_tags(&agent).iter() {
+            out.push(tag_id);
+        }
+        out
+    }
+
+    #[view(getJobRequirements)]
+    fn get_job_requirements(&self, job_id: u64) -> OptionalValue<JobRequirements<Self::Api>> {
+        if self.job_requirements(job_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.job_requirements(job_id).get())
+        }
+    }
+
+    #[view(getApplication)]
+    fn get_application(&self, job_id: u64, application_id: u64) -> OptionalValue<Application<Self::Api>> {
+        if self.applications(job_id, application_id).is_empty() || self.is_legacy_job(job_id) {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.applications(job_id, application_id).get())
+        }
+    }
+
+    #[view(getOffer)]
+    fn get_offer(&self, job_id: u64, offer_id: u64) -> OptionalValue<Offer<Self::Api>> {
+        if self.offers(job_id, offer_id).is_empty() || self.is_legacy_job(job_id) {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.offers(job_id, offer_id).get())
+        }
+    }
+
+    #[view(getApplications)]
+    fn get_applications(
+        &self,
+        job_id: u64,
+        from: u64,
+        size: u64,
+        status_filter: OptionalValue<ApplicationStatus>,
+    ) -> MultiValueEncoded<Application<Self::Api>> {
+        let status_filter = status_filter.into_option();
+        let mut out = MultiValueEncoded::new();
+        let count = self.application_count(job_id).get();
+        if count == 0 || self.is_legacy_job(job_id) {
+            return out;
+        }
+
         let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
         if effective_size == 0 {
             return out;
@@ -562,7 +1105,14 @@ pub trait JobBoardCore {
         let mut emitted = 0;
         while idx <= count && emitted < effective_size {
             if !self.applications(job_id, idx).is_empty() {
-                out.push(self.applications(job_id, idx).get());
+                let application = self.applications(job_id, idx).get();
+                let included = match status_filter {
+                    Some(status) => application.status == status,
+                    None => application.status != ApplicationStatus::Withdrawn,
+                };
+                if included {
+                    out.push(application);
+                }
             }
             idx += 1;
             emitted += 1;
@@ -581,7 +1131,7 @@ pub trait JobBoardCore {
         let mut out = MultiValueEncoded::new();
         let list = self.offers_by_application(job_id, application_id);
         let total = list.len() as u64;
-        if total == 0 {
+        if total == 0 || self.is_legacy_job(job_id) {
             return out;
         }
 
@@ -603,33 +1153,133 @@ pub trait JobBoardCore {
         out
     }
 
+    #[view(getApplicationsByApplicant)]
+    fn get_applications_by_applicant(
+        &self,
+        applicant: ManagedAddress,
+        from: u64,
+        size: u64,
+    ) -> MultiValueEncoded<Application<Self::Api>> {
+        let mut out = MultiValueEncoded::new();
+        let job_ids = self.applied_job_ids(&applicant);
+        let total = job_ids.len() as u64;
+        if total == 0 {
+            return out;
+        }
+
+        let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
+        if effective_size == 0 {
+            return out;
+        }
+
+        let mut emitted = 0;
+        let mut idx = from + 1;
+        while idx <= total && emitted < effective_size {
+            let job_id = job_ids.get_by_index(idx as usize);
+            let application_id = self.application_id_by_applicant(job_id, &applicant).get();
+            if !self.applications(job_id, application_id).is_empty() {
+                out.push(self.applications(job_id, application_id).get());
+            }
+            idx += 1;
+            emitted += 1;
+        }
+        out
+    }
+
+    #[view(getPendingOffersFor)]
+    fn get_pending_offers_for(
+        &self,
+        counterparty: ManagedAddress,
+        from: u64,
+        size: u64,
+    ) -> MultiValueEncoded<Offer<Self::Api>> {
+        let mut out = MultiValueEncoded::new();
+        let pending = self.pending_offers_for(&counterparty);
+        let total = pending.len() as u64;
+        if total == 0 {
+            return out;
+        }
+
+        let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
+        if effective_size == 0 {
+            return out;
+        }
+
+        let now = self.blockchain().get_block_timestamp();
+        let mut emitted = 0;
+        let mut idx = from + 1;
+        while idx <= total && emitted < effective_size {
+            let offer_ref = pending.get_by_index(idx as usize);
+            if !self.offers(offer_ref.job_id, offer_ref.offer_id).is_empty() {
+                let offer = self.offers(offer_ref.job_id, offer_ref.offer_id).get();
+                // Offers past their expiry stay indexed until someone calls expireOffer.
+                if offer.expires_at == 0 || now <= offer.expires_at {
+                    out.push(offer);
+                }
+            }
+            idx += 1;
+            emitted += 1;
+        }
+        out
+    }
+
     #[view(getAcceptedOffer)]
     fn get_accepted_offer(&self, job_id: u64) -> OptionalValue<AcceptedOfferSummary<Self::Api>> {
-        if self.accepted_offer_id(job_id).is_empty() {
+        if self.jobs(job_id).is_empty() || self.is_legacy_job(job_id) {
             return OptionalValue::None;
         }
-        let offer_id = self.accepted_offer_id(job_id).get();
-        if offer_id == 0 || self.offers(job_id, offer_id).is_empty() || self.jobs(job_id).is_empty() {
-            return OptionalValue::None;
+        let offer_id = self.jobs(job_id).get().accepted_offer_id;
+        self.accepted_offer_summary(job_id, offer_id)
+    }
+
+    #[view(getAcceptedOfferForApplication)]
+    fn get_accepted_offer_for_application(
+        &self,
+        job_id: u64,
+        application_id: u64,
+    ) -> OptionalValue<AcceptedOfferSummary<Self::Api>> {
+        let offer_id = self.accepted_offer_by_application(job_id, application_id).get();
+        self.accepted_offer_summary(job_id, offer_id)
+    }
+
+    #[view(getAcceptedOffers)]
+    fn get_accepted_offers(&self, job_id: u64) -> MultiValueEncoded<AcceptedOfferSummary<Self::Api>> {
+        let mut out = MultiValueEncoded::new();
+        for offer_id in self.accepted_offers(job_id).iter() {
+            if let OptionalValue::Some(summary) = self.accepted_offer_summary(job_id, offer_id) {
+                out.push(summary);
+            }
         }
+        out
+    }
 
-        let offer = self.offers(job_id, offer_id).get();
-        let job = self.jobs(job_id).get();
-        let worker = self.application_applicant(job_id, offer.application_id);
+    #[view(getInvites)]
+    fn get_invites(&self, job_id: u64, from: u64, size: u64) -> MultiValueEncoded<ManagedAddress> {
+        let mut out = MultiValueEncoded::new();
+        let invites = self.invites(job_id);
+        let total = invites.len() as u64;
+        if total == 0 {
+            return out;
+        }
 
-        OptionalValue::Some(AcceptedOfferSummary {
-            job_id,
-            offer_id,
-            employer: job.employer,
-            worker,
-            terms: offer.terms,
-            accepted_at: self.accepted_offer_timestamp(job_id).get(),
-        })
+        let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
+        if effective_size == 0 {
+            return out;
+        }
+
+        let mut emitted = 0;
+        let mut idx = from + 1;
+        while idx <= total && emitted < effective_size {
+            out.push(invites.get_by_index(idx as usize));
+            idx += 1;
+            emitted += 1;
+        }
+        out
     }
 
     #[view(isInviteAllowed)]
     fn is_invite_allowed(&self, job_id: u64, addr: ManagedAddress) -> bool {
-        if self.jobs(job_id).is_empty() {
+        if self.jobs(job_id).is_empty() || self.is_legacy_job(job_id) {
             return false;
         }
         let job = self.jobs(job_id).get();
@@ -659,25 +1309,43 @@ pub trait JobBoardCore {
             min_uptime_score: self.min_uptime_score().get(),
             max_counteroffers_per_application: self.max_counteroffers_per_application().get(),
             max_invites_per_job: self.max_invites_per_job().get(),
+            escrow: if self.escrow().is_empty() {
+                ManagedAddress::zero()
+            } else {
+                self.escrow().get()
+            },
             paused: self.paused().get(),
         }
     }
 
     fn require_job(&self, job_id: u64) -> Job<Self::Api> {
         require!(!self.jobs(job_id).is_empty(), ERR_INVALID_STATE);
+        require!(!self.is_legacy_job(job_id), ERR_MIGRATION_PENDING);
         self.jobs(job_id).get()
     }
 
+    fn require_open_job_for_employer(&self, job_id: u64) -> Job<Self::Api> {
+        let job = self.require_job(job_id);
+        require!(self.blockchain().get_caller() == job.employer, ERR_UNAUTHORIZED);
+        require!(
+            job.status == JobStatus::Open || job.status == JobStatus::InNegotiation,
+            ERR_INVALID_STATE
+        );
+        job
+    }
+
     fn require_application(&self, job_id: u64, application_id: u64) -> Application<Self::Api> {
         require!(
             !self.applications(job_id, application_id).is_empty(),
             ERR_INVALID_STATE
         );
+        require!(!self.is_legacy_job(job_id), ERR_MIGRATION_PENDING);
         self.applications(job_id, application_id).get()
     }
 
     fn require_offer(&self, job_id: u64, offer_id: u64) -> Offer<Self::Api> {
         require!(!self.offers(job_id, offer_id).is_empty(), ERR_INVALID_STATE);
+        require!(!self.is_legacy_job(job_id), ERR_MIGRATION_PENDING);
         self.offers(job_id, offer_id).get()
     }
 
@@ -700,21 +1368,73 @@ pub trait JobBoardCore {
     }
 
     fn terms_input_to_terms(&self, terms: OfferTermsInput<Self::Api>) -> OfferTerms<Self::Api> {
-        OfferTerms {
+        // Omitted values take the escrow defaults in force now, so later risk
+        // param changes cannot rewrite what the parties agreed to.
+        let (notice_seconds, termination_penalty_bps) =
+            match (terms.notice_seconds, terms.termination_penalty_bps) {
+                (Some(notice), Some(penalty)) => (notice, penalty),
+                (notice, penalty) => {
+                    require!(!self.escrow().is_empty(), ERR_INVALID_STATE);
+                    let config = self
+                        .tx()
+                        .to(self.escrow().get())
+                        .typed(WorkEscrowProxy)
+                        .get_config()
+                        .returns(ReturnsResult)
+                        .sync_call_readonly();
+                    (
+                        notice.unwrap_or(config.default_notice_seconds),
+                        penalty.unwrap_or(config.termination_penalty_bps),
+                    )
+                }
+            };
+
+        let offer_terms = OfferTerms {
+            payment_token: terms.payment_token,
             recurring: terms.recurring,
             revenue_share: terms.revenue_share,
             employer_bond_required: terms.employer_bond_required,
             worker_bond_required: terms.worker_bond_required,
+            notice_seconds,
+            termination_penalty_bps,
             milestones: terms.milestones,
             terms_uri: terms.terms_uri,
+        };
+        self.require_escrow_accepts_terms(&offer_terms);
+        offer_terms
+    }
+
+    fn require_escrow_accepts_terms(&self, terms: &OfferTerms<Self::Api>) {
+        // Terms the escrow would refuse at activation would leave a seat filled forever.
+        if self.escrow().is_empty() {
+            return;
         }
+
+        let activatable = self
+            .tx()
+            .to(self.escrow().get())
+            .typed(WorkEscrowProxy)
+            .offer_terms_activatable(terms)
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        require!(activatable, ERR_TERMS_NOT_ACTIVATABLE);
     }
 
-    fn validate_offer_terms(&self, terms: &OfferTermsInput<Self::Api>) {
+    fn validate_offer_terms(&self, job: &Job<Self::Api>, terms: &OfferTermsInput<Self::Api>) {
+        require!(terms.payment_token.is_valid(), ERR_INVALID_TOKEN);
+        require!(
+            terms.expires_at == 0 || terms.expires_at > self.blockchain().get_block_timestamp(),
+            ERR_INVALID_DEADLINE
+        );
+        self.require_comp_modes_allowed(job, terms);
         require!(
             terms.revenue_share.profit_share_bps <= BPS_DENOMINATOR,
             ERR_INVALID_BPS
         );
+        require!(
+            terms.termination_penalty_bps.unwrap_or(0) <= BPS_DENOMINATOR,
+            ERR_INVALID_BPS
+        );
         require!(terms.terms_uri.len() <= MAX_TERMS_URI_LEN, ERR_INVALID_AMOUNT);
         require!(
             terms.milestones.len() <= MAX_MILESTONES_PER_OFFER,
@@ -733,15 +1453,411 @@ pub trait JobBoardCore {
         }
     }
 
+    fn require_comp_modes_allowed(&self, job: &Job<Self::Api>, terms: &OfferTermsInput<Self::Api>) {
+        let mut used_modes = 0u8;
+        if terms.recurring.amount_per_period > 0u64 {
+            used_modes |= COMP_MODE_RECURRING;
+        }
+        if terms.revenue_share.profit_share_bps > 0 {
+            used_modes |= COMP_MODE_REVENUE_SHARE;
+        }
+        if !terms.milestones.is_empty() {
+            used_modes |= COMP_MODE_MILESTONE;
+        }
+        require!(
+            used_modes & !job.comp_mode_mask == 0,
+            ERR_COMP_MODE_NOT_ALLOWED
+        );
+    }
+
     fn validate_milestone_spec(&self, milestone: &MilestoneSpec<Self::Api>) {
         require!(milestone.amount > 0u64, ERR_INVALID_AMOUNT);
         require!(milestone.review_timeout_seconds > 0, ERR_INVALID_AMOUNT);
+        // A zero due date means no deadline; anything else must be reachable.
+        require!(
+            milestone.due_ts
+                <= self.blockchain().get_block_timestamp() + MAX_MILESTONE_DUE_HORIZON_SECONDS,
+            ERR_INVALID_DEADLINE
+        );
         require!(
             milestone.metadata_uri.len() <= MAX_METADATA_URI_LEN,
             ERR_INVALID_AMOUNT
         );
     }
 
+    fn validate_job_requirements(&self, requirements: &JobRequirements<Self::Api>) {
+        let tag_count = requirements.skill_tag_ids.len();
+        require!(tag_count <= MAX_SKILL_TAGS_PER_JOB, ERR_INVALID_AMOUNT);
+        for i in 0..tag_count {
+            let tag_id = requirements.skill_tag_ids.get(i);
+            self.require_skill_tag(tag_id);
+            for j in (i + 1)..tag_count {
+                require!(requirements.skill_tag_ids.get(j) != tag_id, ERR_INVALID_STATE);
+            }
+        }
+
+        require!(
+            requirements.budget_min <= requirements.budget_max,
+            ERR_INVALID_AMOUNT
+        );
+        require!(
+            requirements.min_reputation_score <= SCORE_MAX,
+            ERR_INVALID_AMOUNT
+        );
+        if self.requires_reputation_check(requirements) {
+            require!(!self.escrow().is_empty(), ERR_INVALID_STATE);
+        }
+    }
+
+    fn requires_reputation_check(&self, requirements: &JobRequirements<Self::Api>) -> bool {
+        requirements.min_reputation_score > 0 || requirements.max_worker_defaults.is_some()
+    }
+
+    fn require_reputation_admission(&self, job_id: u64, agent: &ManagedAddress) {
+        if self.job_requirements(job_id).is_empty() {
+            return;
+        }
+        let requirements = self.job_requirements(job_id).get();
+        if !self.requires_reputation_check(&requirements) {
+            return;
+        }
+
+        let reputation = self
+            .tx()
+            .to(self.escrow().get())
+            .typed(WorkEscrowProxy)
+            .get_agent_reputation(agent.clone())
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        require!(
+            reputation.score >= requirements.min_reputation_score,
+            ERR_LOW_REPUTATION
+        );
+        if let Some(max_worker_defaults) = requirements.max_worker_defaults {
+            require!(
+                reputation.defaults_as_worker <= max_worker_defaults,
+                ERR_TOO_MANY_DEFAULTS
+            );
+        }
+    }
+
+    fn require_skill_tag(&self, tag_id: u64) {
+        require!(
+            tag_id > 0 && tag_id <= self.skill_tag_count().get(),
+            ERR_UNKNOWN_SKILL_TAG
+        );
+    }
+
+    fn job_matches_requirements_filter(
+        &self,
+        job_id: u64,
+        filter: &JobRequirementsFilter<Self::Api>,
+    ) -> bool {
+        // Zero-valued filter fields are ignored; the reputation score is always applied.
+        if self.job_requirements(job_id).is_empty() {
+            return filter.skill_tag_id == 0
+                && filter.min_budget == 0u64
+                && filter.max_duration_seconds == 0;
+        }
+
+        let requirements = self.job_requirements(job_id).get();
+        if filter.skill_tag_id != 0
+            && !requirements
+                .skill_tag_ids
+                .iter()
+                .any(|tag_id| tag_id == filter.skill_tag_id)
+        {
+            return false;
+        }
+        if filter.min_budget > 0u64 && requirements.budget_max < filter.min_budget {
+            return false;
+        }
+        if filter.max_duration_seconds > 0
+            && requirements.expected_duration_seconds > filter.max_duration_seconds
+        {
+            return false;
+        }
+        requirements.min_reputation_score <= filter.reputation_score
+    }
+
+    fn collect_job_page<F: Fn(&Job<Self::Api>) -> bool>(
+        &self,
+        index: VecMapper<u64>,
+        from: u64,
+        size: u64,
+        include: F,
+    ) -> MultiValueEncoded<Job<Self::Api>> {
+        let mut out = MultiValueEncoded::new();
+        let total = index.len() as u64;
+        if total == 0 {
+            return out;
+        }
+
+        let effective_size = core::cmp::min(size, MAX_PAGE_SIZE);
+        if effective_size == 0 {
+            return out;
+        }
+
+        let mut emitted = 0;
+        let mut idx = from + 1;
+        while idx <= total && emitted < effective_size {
+            let job_id = index.get(idx as usize);
+            if !self.jobs(job_id).is_empty() && !self.is_legacy_job(job_id) {
+                let job = self.jobs(job_id).get();
+                if include(&job) {
+                    out.push(job);
+                }
+            }
+            idx += 1;
+            emitted += 1;
+        }
+        out
+    }
+
+    fn accepted_offer_summary(
+        &self,
+        job_id: u64,
+        offer_id: u64,
+    ) -> OptionalValue<AcceptedOfferSummary<Self::Api>> {
+        if offer_id == 0
+            || self.is_legacy_job(job_id)
+            || !self.accepted_offers(job_id).contains(&offer_id)
+            || self.offers(job_id, offer_id).is_empty()
+            || self.jobs(job_id).is_empty()
+        {
+            return OptionalValue::None;
+        }
+
+        let offer = self.offers(job_id, offer_id).get();
+        let job = self.jobs(job_id).get();
+        let worker = self.application_applicant(job_id, offer.application_id);
+
+        OptionalValue::Some(AcceptedOfferSummary {
+            job_id,
+            offer_id,
+            employer: job.employer,
+            worker,
+            terms: offer.terms,
+            accepted_at: self.accepted_offer_timestamp(job_id, offer_id).get(),
+        })
+    }
+
+    fn is_legacy_job(&self, job_id: u64) -> bool {
+        job_id > self.legacy_migration_cursor().get() && job_id <= self.legacy_job_watermark().get()
+    }
+
+    fn migrate_legacy_job(&self, job_id: u64, notice_seconds: u64, termination_penalty_bps: u64) {
+        if self.legacy_jobs(job_id).is_empty() {
+            return;
+        }
+        let legacy = self.legacy_jobs(job_id).get();
+        let accepted_offer_id = legacy.accepted_offer_id;
+        self.legacy_accepted_offer_id(job_id).clear();
+        let accepted_at = self.legacy_accepted_offer_timestamp(job_id).take();
+
+        let negotiating =
+            legacy.status == JobStatus::Open || legacy.status == JobStatus::InNegotiation;
+        for application_id in 1..=self.application_count(job_id).get() {
+            if self.applications(job_id, application_id).is_empty() {
+                continue;
+            }
+            self.migrate_legacy_application(job_id, application_id);
+
+            let latest_offer_id = self.latest_offer(job_id, application_id).get();
+            for offer_id in self.offers_by_application(job_id, application_id).iter() {
+                if self.offers(job_id, offer_id).is_empty() {
+                    continue;
+                }
+                let offer = self.migrate_legacy_offer(
+                    job_id,
+                    offer_id,
+                    notice_seconds,
+                    termination_penalty_bps,
+                );
+                let live = offer.status == OfferStatus::Proposed
+                    || offer.status == OfferStatus::Countered;
+                if negotiating && live && offer_id == latest_offer_id {
+                    self.track_pending_offer(&offer);
+                }
+            }
+        }
+
+        // Legacy jobs had a single seat, filled by the job's accepted offer.
+        let matched = legacy.status == JobStatus::Matched && accepted_offer_id != 0;
+        if matched && !self.offers(job_id, accepted_offer_id).is_empty() {
+            let offer = self.offers(job_id, accepted_offer_id).get();
+            self.accepted_offers(job_id).insert(accepted_offer_id);
+            self.accepted_offer_by_application(job_id, offer.application_id)
+                .set(accepted_offer_id);
+            self.accepted_offer_timestamp(job_id, accepted_offer_id)
+                .set(accepted_at);
+        }
+
+        self.jobs(job_id).set(Job {
+            id: legacy.id,
+            employer: legacy.employer,
+            metadata_uri: legacy.metadata_uri,
+            visibility: legacy.visibility,
+            application_deadline_ts: legacy.application_deadline_ts,
+            min_worker_uptime: legacy.min_worker_uptime,
+            comp_mode_mask: legacy.comp_mode_mask,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            accepted_offer_id,
+            application_count: legacy.application_count,
+            seats: 1,
+            filled_seats: if matched { 1 } else { 0 },
+            revision: 0,
+        });
+    }
+
+    fn migrate_legacy_application(&self, job_id: u64, application_id: u64) {
+        let legacy: LegacyApplication<Self::Api> =
+            self.legacy_applications(job_id, application_id).get();
+        self.applied_job_ids(&legacy.applicant).insert(job_id);
+        self.application_id_by_applicant(job_id, &legacy.applicant)
+            .set(application_id);
+        self.applications(job_id, application_id).set(Application {
+            id: legacy.id,
+            job_id: legacy.job_id,
+            applicant: legacy.applicant,
+            application_uri: legacy.application_uri,
+            created_at: legacy.created_at,
+            status: ApplicationStatus::Pending,
+            status_reason: ManagedBuffer::new(),
+        });
+    }
+
+    fn migrate_legacy_offer(
+        &self,
+        job_id: u64,
+        offer_id: u64,
+        notice_seconds: u64,
+        termination_penalty_bps: u64,
+    ) -> Offer<Self::Api> {
+        let legacy: LegacyOffer<Self::Api> = self.legacy_offers(job_id, offer_id).get();
+
+        // Legacy offers paid in EGLD and never expired.
+        let offer = Offer {
+            id: legacy.id,
+            job_id: legacy.job_id,
+            application_id: legacy.application_id,
+            proposer: legacy.proposer,
+            counterparty: legacy.counterparty,
+            party: legacy.party,
+            parent_offer_id: legacy.parent_offer_id,
+            round_index: legacy.round_index,
+            terms: OfferTerms {
+                payment_token: EgldOrEsdtTokenIdentifier::egld(),
+                recurring: legacy.terms.recurring,
+                revenue_share: legacy.terms.revenue_share,
+                employer_bond_required: legacy.terms.employer_bond_required,
+                worker_bond_required: legacy.terms.worker_bond_required,
+                notice_seconds,
+                termination_penalty_bps,
+                milestones: legacy.terms.milestones,
+                terms_uri: legacy.terms.terms_uri,
+            },
+            status: legacy.status,
+            created_at: legacy.created_at,
+            expires_at: 0,
+        };
+        self.offers(job_id, offer_id).set(&offer);
+        offer
+    }
+
+    fn track_pending_offer(&self, offer: &Offer<Self::Api>) {
+        self.pending_offers_for(&offer.counterparty).insert(OfferRef {
+            job_id: offer.job_id,
+            offer_id: offer.id,
+        });
+    }
+
+    fn untrack_pending_offer(&self, offer: &Offer<Self::Api>) {
+        self.pending_offers_for(&offer.counterparty).swap_remove(&OfferRef {
+            job_id: offer.job_id,
+            offer_id: offer.id,
+        });
+    }
+
+    fn void_live_offer(&self, job_id: u64, application_id: u64) -> u64 {
+        let mut voided_offer_id = 0;
+        let latest_offer_id = self.latest_offer(job_id, application_id).get();
+        if latest_offer_id != 0 && !self.offers(job_id, latest_offer_id).is_empty() {
+            let mut latest_offer = self.offers(job_id, latest_offer_id).get();
+            if latest_offer.status == OfferStatus::Proposed
+                || latest_offer.status == OfferStatus::Countered
+            {
+                latest_offer.status = OfferStatus::Void;
+                self.untrack_pending_offer(&latest_offer);
+                self.offers(job_id, latest_offer_id).set(latest_offer);
+                voided_offer_id = latest_offer_id;
+            }
+        }
+        self.latest_offer(job_id, application_id).clear();
+        voided_offer_id
+    }
+
+    fn is_application_live(&self, job_id: u64, application_id: u64) -> bool {
+        let status = self.applications(job_id, application_id).get().status;
+        status == ApplicationStatus::Pending || status == ApplicationStatus::Shortlisted
+    }
+
+    fn resolve_status_reason(&self, reason: OptionalValue<ManagedBuffer>) -> ManagedBuffer {
+        match reason {
+            OptionalValue::Some(value) => {
+                require!(value.len() <= MAX_REASON_URI_LEN, ERR_INVALID_AMOUNT);
+                value
+            }
+            OptionalValue::None => ManagedBuffer::new(),
+        }
+    }
+
+    fn close_sibling_negotiations(&self, job_id: u64) -> u64 {
+        // Bounded per call; closeSiblingNegotiations continues from the cursor.
+        let ts = self.blockchain().get_block_timestamp();
+        let count = self.application_count(job_id).get();
+        let from = self.sibling_close_cursor(job_id).get() + 1;
+        let to = core::cmp::min(count, from + MAX_SIBLING_CLOSE_BATCH - 1);
+        for application_id in from..=to {
+            if self.applications(job_id, application_id).is_empty()
+                || !self.is_application_live(job_id, application_id)
+                || !self.accepted_offer_by_application(job_id, application_id).is_empty()
+            {
+                continue;
+            }
+
+            let mut superseded_offer_id = 0;
+            let latest_offer_id = self.latest_offer(job_id, application_id).get();
+            if latest_offer_id != 0 && !self.offers(job_id, latest_offer_id).is_empty() {
+                let mut latest_offer = self.offers(job_id, latest_offer_id).get();
+                if latest_offer.status == OfferStatus::Proposed
+                    || latest_offer.status == OfferStatus::Countered
+                {
+                    latest_offer.status = OfferStatus::Superseded;
+                    self.untrack_pending_offer(&latest_offer);
+                    self.offers(job_id, latest_offer_id).set(latest_offer);
+                    superseded_offer_id = latest_offer_id;
+                }
+            }
+
+            self.negotiation_closed_event(job_id, application_id, superseded_offer_id, ts);
+        }
+
+        if to >= from {
+            self.sibling_close_cursor(job_id).set(to);
+        }
+        count.saturating_sub(self.sibling_close_cursor(job_id).get())
+    }
+
+    fn require_offer_not_expired(&self, offer: &Offer<Self::Api>) {
+        require!(
+            offer.expires_at == 0 || self.blockchain().get_block_timestamp() <= offer.expires_at,
+            ERR_OFFER_EXPIRED
+        );
+    }
+
     fn require_no_active_latest_offer(&self, job_id: u64, application_id: u64) {
         if self.latest_offer(job_id, application_id).is_empty() {
             return;
@@ -782,6 +1898,43 @@ pub trait JobBoardCore {
 
     #[event("jobCreated")]
     fn job_created_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] visibility: JobVisibility,
+        #[indexed] requirements: &Option<JobRequirements<Self::Api>>,
+        timestamp: u64,
+    );
+
+    #[event("jobUpdated")]
+    fn job_updated_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] revision: u64,
+        timestamp: u64,
+    );
+
+    #[event("invitesAdded")]
+    fn invites_added_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] count: u64,
+        timestamp: u64,
+    );
+
+    #[event("invitesRemoved")]
+    fn invites_removed_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] count: u64,
+        timestamp: u64,
+    );
+
+    #[event("jobVisibilityChanged")]
+    fn job_visibility_changed_event(
         &self,
         #[indexed] job_id: u64,
         #[indexed] employer: &ManagedAddress,
@@ -798,6 +1951,35 @@ pub trait JobBoardCore {
         timestamp: u64,
     );
 
+    #[event("applicationWithdrawn")]
+    fn application_withdrawn_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] application_id: u64,
+        #[indexed] applicant: &ManagedAddress,
+        #[indexed] voided_offer_id: u64,
+        timestamp: u64,
+    );
+
+    #[event("applicationShortlisted")]
+    fn application_shortlisted_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] application_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        timestamp: u64,
+    );
+
+    #[event("applicationRejected")]
+    fn application_rejected_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] application_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] voided_offer_id: u64,
+        timestamp: u64,
+    );
+
     #[event("offerProposed")]
     fn offer_proposed_event(
         &self,
@@ -827,6 +2009,15 @@ pub trait JobBoardCore {
         timestamp: u64,
     );
 
+    #[event("offerExpired")]
+    fn offer_expired_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] offer_id: u64,
+        #[indexed] by: &ManagedAddress,
+        timestamp: u64,
+    );
+
     #[event("offerAccepted")]
     fn offer_accepted_event(
         &self,
@@ -836,6 +2027,47 @@ pub trait JobBoardCore {
         timestamp: u64,
     );
 
+    #[event("negotiationClosed")]
+    fn negotiation_closed_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] application_id: u64,
+        #[indexed] superseded_offer_id: u64,
+        timestamp: u64,
+    );
+
+    #[event("jobReopened")]
+    fn job_reopened_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] voided_offer_id: u64,
+        timestamp: u64,
+    );
+
+    #[event("skillTagRegistered")]
+    fn skill_tag_registered_event(
+        &self,
+        #[indexed] tag_id: u64,
+        #[indexed] name: &ManagedBuffer,
+        timestamp: u64,
+    );
+
+    #[event("agentSkillTagsUpdated")]
+    fn agent_skill_tags_updated_event(
+        &self,
+        #[indexed] agent: &ManagedAddress,
+        #[indexed] tag_count: u64,
+        timestamp: u64,
+    );
+
+    #[event("legacyJobsMigrated")]
+    fn legacy_jobs_migrated_event(
+        &self,
+        #[indexed] from_job_id: u64,
+        #[indexed] to_job_id: u64,
+        timestamp: u64,
+    );
+
     #[event("jobClosed")]
     fn job_closed_event(
         &self,
@@ -857,6 +2089,9 @@ pub trait JobBoardCore {
     #[storage_mapper("uptime")]
     fn uptime(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[storage_mapper("escrow")]
+    fn escrow(&self) -> SingleValueMapper<ManagedAddress>;
+
     #[storage_mapper("minUptimeScore")]
     fn min_uptime_score(&self) -> SingleValueMapper<u64>;
 
@@ -884,6 +2119,36 @@ pub trait JobBoardCore {
     #[storage_mapper("jobs")]
     fn jobs(&self, job_id: u64) -> SingleValueMapper<Job<Self::Api>>;
 
+    #[storage_mapper("jobs")]
+    fn legacy_jobs(&self, job_id: u64) -> SingleValueMapper<LegacyJob<Self::Api>>;
+
+    #[storage_mapper("storageLayoutVersion")]
+    fn storage_layout_version(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyJobWatermark")]
+    fn legacy_job_watermark(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyMigrationCursor")]
+    fn legacy_migration_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("skillTagCount")]
+    fn skill_tag_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("skillTagName")]
+    fn skill_tag_name(&self, tag_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("skillTagIdByName")]
+    fn skill_tag_id_by_name(&self, name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("agentSkillTags")]
+    fn agent_skill_tags(&self, agent: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("jobsByTag")]
+    fn jobs_by_tag(&self, tag_id: u64) -> VecMapper<u64>;
+
+    #[storage_mapper("jobRequirements")]
+    fn job_requirements(&self, job_id: u64) -> SingleValueMapper<JobRequirements<Self::Api>>;
+
     #[storage_mapper("jobIdsByEmployer")]
     fn job_ids_by_employer(&self, employer: &ManagedAddress) -> VecMapper<u64>;
 
@@ -896,6 +2161,13 @@ pub trait JobBoardCore {
     #[storage_mapper("applications")]
     fn applications(&self, job_id: u64, application_id: u64) -> SingleValueMapper<Application<Self::Api>>;
 
+    #[storage_mapper("applications")]
+    fn legacy_applications(
+        &self,
+        job_id: u64,
+        application_id: u64,
+    ) -> SingleValueMapper<LegacyApplication<Self::Api>>;
+
     #[storage_mapper("hasApplied")]
     fn has_applied(&self, job_id: u64, applicant: &ManagedAddress) -> SingleValueMapper<bool>;
 
@@ -905,18 +2177,47 @@ pub trait JobBoardCore {
     #[storage_mapper("offers")]
     fn offers(&self, job_id: u64, offer_id: u64) -> SingleValueMapper<Offer<Self::Api>>;
 
+    #[storage_mapper("offers")]
+    fn legacy_offers(&self, job_id: u64, offer_id: u64) -> SingleValueMapper<LegacyOffer<Self::Api>>;
+
     #[storage_mapper("latestOffer")]
     fn latest_offer(&self, job_id: u64, application_id: u64) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("acceptedOffers")]
+    fn accepted_offers(&self, job_id: u64) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("acceptedOfferByApplication")]
+    fn accepted_offer_by_application(&self, job_id: u64, application_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("acceptedOfferTimestamp")]
+    fn accepted_offer_timestamp(&self, job_id: u64, offer_id: u64) -> SingleValueMapper<u64>;
+
+    // Single-seat accepted offer per job, replaced by acceptedOffers.
     #[storage_mapper("acceptedOfferId")]
-    fn accepted_offer_id(&self, job_id: u64) -> SingleValueMapper<u64>;
+    fn legacy_accepted_offer_id(&self, job_id: u64) -> SingleValueMapper<u64>;
 
     #[storage_mapper("acceptedOfferTimestamp")]
-    fn accepted_offer_timestamp(&self, job_id: u64) -> SingleValueMapper<u64>;
+    fn legacy_accepted_offer_timestamp(&self, job_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("siblingCloseCursor")]
+    fn sibling_close_cursor(&self, job_id: u64) -> SingleValueMapper<u64>;
 
     #[storage_mapper("counterCount")]
     fn counter_count(&self, job_id: u64, application_id: u64) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("appliedJobIds")]
+    fn applied_job_ids(&self, applicant: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("applicationIdByApplicant")]
+    fn application_id_by_applicant(
+        &self,
+        job_id: u64,
+        applicant: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pendingOffersFor")]
+    fn pending_offers_for(&self, counterparty: &ManagedAddress) -> UnorderedSetMapper<OfferRef>;
+
     #[storage_mapper("jobIndex")]
     fn job_index(&self) -> VecMapper<u64>;
 
@@ -0,0 +1,79 @@
+use multiversx_sc::proxy_imports::*;
+use shared_types::{EscrowConfig, OfferTerms, ReputationSnapshot};
+
+pub struct WorkEscrowProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for WorkEscrowProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = WorkEscrowProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        WorkEscrowProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct WorkEscrowProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+impl<Env, From, To, Gas> WorkEscrowProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn get_agent_reputation<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
+        self,
+        agent: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReputationSnapshot> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAgentReputation")
+            .argument(&agent)
+            .original_result()
+    }
+
+    pub fn get_config(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, EscrowConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getConfig")
+            .original_result()
+    }
+
+    pub fn offer_released<Arg0: ProxyArg<u64>, Arg1: ProxyArg<u64>>(
+        self,
+        job_id: Arg0,
+        offer_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOfferReleased")
+            .argument(&job_id)
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_terms_activatable<Arg0: ProxyArg<OfferTerms<Env::Api>>>(
+        self,
+        terms: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOfferTermsActivatable")
+            .argument(&terms)
+            .original_result()
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           23
+// Endpoints:                           49
 // Async Callback (empty):               1
-// Total number of exported functions:  26
+// Total number of exported functions:  52
 
 #![no_std]
 
@@ -21,25 +21,51 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         createJob => create_job
+        updateJob => update_job
+        addInvites => add_invites
+        removeInvites => remove_invites
+        setJobVisibility => set_job_visibility
+        setAgentSkillTags => set_agent_skill_tags
         apply => apply
+        withdrawApplication => withdraw_application
+        shortlistApplication => shortlist_application
+        rejectApplication => reject_application
         proposeOffer => propose_offer
         counterOffer => counter_offer
         rejectOffer => reject_offer
         withdrawOffer => withdraw_offer
         acceptOffer => accept_offer
+        closeSiblingNegotiations => close_sibling_negotiations_endpoint
+        expireOffer => expire_offer
         cancelJob => cancel_job
         expireJob => expire_job
+        reopenMatchedJob => reopen_matched_job
+        setEscrow => set_escrow
         setMinUptimeScore => set_min_uptime_score
         setMaxCounteroffersPerApplication => set_max_counteroffers_per_application
         setMaxInvitesPerJob => set_max_invites_per_job
         setPaused => set_paused
         setOwner => set_owner
+        registerSkillTag => register_skill_tag
+        migrateLegacyJobs => migrate_legacy_jobs
         getJob => get_job
+        getJobs => get_jobs
+        getJobsByEmployer => get_jobs_by_employer
+        getOpenJobs => get_open_jobs
+        getJobsByTag => get_jobs_by_tag
+        getSkillTags => get_skill_tags
+        getAgentSkillTags => get_agent_skill_tags
+        getJobRequirements => get_job_requirements
         getApplication => get_application
         getOffer => get_offer
         getApplications => get_applications
         getOffers => get_offers
+        getApplicationsByApplicant => get_applications_by_applicant
+        getPendingOffersFor => get_pending_offers_for
         getAcceptedOffer => get_accepted_offer
+        getAcceptedOfferForApplication => get_accepted_offer_for_application
+        getAcceptedOffers => get_accepted_offers
+        getInvites => get_invites
         isInviteAllowed => is_invite_allowed
         getBoardStats => get_board_stats
         getConfig => get_config
@@ -12,6 +12,15 @@ pub const MAX_TERMS_URI_LEN: usize = 512;
 pub const MAX_REASON_URI_LEN: usize = 512;
 pub const MAX_PROOF_URI_LEN: usize = 512;
 pub const MAX_APPLICATION_URI_LEN: usize = 512;
+pub const MAX_SEATS_PER_JOB: u64 = 32;
+pub const MAX_SKILL_TAGS_PER_JOB: usize = 8;
+pub const MAX_SKILL_TAGS_PER_AGENT: usize = 16;
+pub const MAX_SKILL_TAG_NAME_LEN: usize = 64;
+
+pub const COMP_MODE_RECURRING: u8 = 0b001;
+pub const COMP_MODE_REVENUE_SHARE: u8 = 0b010;
+pub const COMP_MODE_MILESTONE: u8 = 0b100;
+pub const COMP_MODE_ALL: u8 = COMP_MODE_RECURRING | COMP_MODE_REVENUE_SHARE | COMP_MODE_MILESTONE;
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +39,15 @@ pub enum JobStatus {
     Expired,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationStatus {
+    Pending,
+    Withdrawn,
+    Shortlisted,
+    Rejected,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
 pub enum OfferStatus {
@@ -38,6 +56,9 @@ pub enum OfferStatus {
     Accepted,
     Rejected,
     Withdrawn,
+    Void,
+    Expired,
+    Superseded,
 }
 
 #[type_abi]
@@ -55,6 +76,7 @@ pub enum AgreementStatus {
     NoticePeriod,
     Terminated,
     Completed,
+    Cancelled,
 }
 
 #[type_abi]
@@ -64,6 +86,8 @@ pub enum MilestoneState {
     Submitted,
     Rejected,
     Paid,
+    Disputed,
+    Cancelled,
 }
 
 #[type_abi]
@@ -71,6 +95,7 @@ pub enum MilestoneState {
 pub enum MilestoneSettlementMode {
     Approved,
     AutoApproved,
+    Arbitrated,
 }
 
 #[type_abi]
@@ -88,6 +113,7 @@ pub enum TerminationReason {
     EmployerDefault,
     WorkerDefault,
     NaturalCompletion,
+    Mutual,
 }
 
 #[type_abi]
@@ -101,6 +127,7 @@ pub enum ReputationReason {
     WorkerDefault,
     UnilateralTerminate,
     Completion,
+    DisputeLost,
 }
 
 #[type_abi]
@@ -124,6 +151,29 @@ pub struct Job<M: ManagedTypeApi> {
     pub created_at: u64,
     pub accepted_offer_id: u64,
     pub application_count: u64,
+    pub seats: u64,
+    pub filled_seats: u64,
+    pub revision: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct JobRequirements<M: ManagedTypeApi> {
+    pub skill_tag_ids: ManagedVec<M, u64>,
+    pub budget_min: BigUint<M>,
+    pub budget_max: BigUint<M>,
+    pub expected_duration_seconds: u64,
+    pub min_reputation_score: u64,
+    pub max_worker_defaults: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct JobRequirementsFilter<M: ManagedTypeApi> {
+    pub skill_tag_id: u64,
+    pub min_budget: BigUint<M>,
+    pub max_duration_seconds: u64,
+    pub reputation_score: u64,
 }
 
 #[type_abi]
@@ -134,6 +184,8 @@ pub struct Application<M: ManagedTypeApi> {
     pub applicant: ManagedAddress<M>,
     pub application_uri: ManagedBuffer<M>,
     pub created_at: u64,
+    pub status: ApplicationStatus,
+    pub status_reason: ManagedBuffer<M>,
 }
 
 #[type_abi]
@@ -163,10 +215,13 @@ pub struct RevenueShareTerms {
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct OfferTerms<M: ManagedTypeApi> {
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
     pub recurring: RecurringTerms<M>,
     pub revenue_share: RevenueShareTerms,
     pub employer_bond_required: BigUint<M>,
     pub worker_bond_required: BigUint<M>,
+    pub notice_seconds: u64,
+    pub termination_penalty_bps: u64,
     pub milestones: ManagedVec<M, MilestoneSpec<M>>,
     pub terms_uri: ManagedBuffer<M>,
 }
@@ -174,10 +229,14 @@ pub struct OfferTerms<M: ManagedTypeApi> {
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct OfferTermsInput<M: ManagedTypeApi> {
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub expires_at: u64,
     pub recurring: RecurringTerms<M>,
     pub revenue_share: RevenueShareTerms,
     pub employer_bond_required: BigUint<M>,
     pub worker_bond_required: BigUint<M>,
+    pub notice_seconds: Option<u64>,
+    pub termination_penalty_bps: Option<u64>,
     pub milestones: ManagedVec<M, MilestoneSpec<M>>,
     pub terms_uri: ManagedBuffer<M>,
 }
@@ -196,6 +255,14 @@ pub struct Offer<M: ManagedTypeApi> {
     pub terms: OfferTerms<M>,
     pub status: OfferStatus,
     pub created_at: u64,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub struct OfferRef {
+    pub job_id: u64,
+    pub offer_id: u64,
 }
 
 #[type_abi]
@@ -228,6 +295,7 @@ pub struct JobBoardConfig<M: ManagedTypeApi> {
     pub min_uptime_score: u64,
     pub max_counteroffers_per_application: u64,
     pub max_invites_per_job: u64,
+    pub escrow: ManagedAddress<M>,
     pub paused: bool,
 }
 
@@ -248,18 +316,32 @@ pub struct Agreement<M: ManagedTypeApi> {
     pub requested_by_side: u8,
     pub default_side: u8,
     pub terms: AgreementTerms<M>,
+    pub funding_deadline_ts: u64,
 }
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct AgreementTerms<M: ManagedTypeApi> {
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
     pub recurring: RecurringTermsEscrow<M>,
     pub revenue_share: RevenueShareTermsEscrow,
     pub employer_bond_required: BigUint<M>,
     pub worker_bond_required: BigUint<M>,
+    pub notice_seconds: u64,
+    pub termination_penalty_bps: u64,
     pub milestone_count: u64,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MutualTerminationProposal<M: ManagedTypeApi> {
+    pub proposer_side: u8,
+    pub end_ts: u64,
+    pub confirm_deadline_ts: u64,
+    pub worker_settlement: BigUint<M>,
+    pub proposed_at: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct RecurringTermsEscrow<M: ManagedTypeApi> {
@@ -303,6 +385,29 @@ pub struct Milestone<M: ManagedTypeApi> {
     pub reason_uri: ManagedBuffer<M>,
     pub settlement_mode: u8,
     pub paid_at: u64,
+    pub revision_count: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MilestoneRevision<M: ManagedTypeApi> {
+    pub round: u64,
+    pub proof_uri: ManagedBuffer<M>,
+    pub reason_uri: ManagedBuffer<M>,
+    pub submitted_at: u64,
+    pub rejected_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Dispute<M: ManagedTypeApi> {
+    pub agreement_id: u64,
+    pub milestone_id: u64,
+    pub reason_uri: ManagedBuffer<M>,
+    pub raised_at: u64,
+    pub resolved_at: u64,
+    pub arbitrator: ManagedAddress<M>,
+    pub worker_share_bps: u64,
 }
 
 #[type_abi]
@@ -361,6 +466,11 @@ pub struct EscrowConfig<M: ManagedTypeApi> {
     pub termination_penalty_bps: u64,
     pub milestone_review_timeout_seconds: u64,
     pub max_milestones_per_agreement: u64,
+    pub max_revision_rounds: u64,
+    pub funding_window_seconds: u64,
+    pub worker_default_grace_seconds: u64,
+    pub max_notice_seconds: u64,
+    pub termination_cancel_requires_consent: bool,
     pub score_start: u64,
     pub paused: bool,
 }
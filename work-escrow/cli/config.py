@@ -11,20 +11,41 @@ DEFAULT_GAS_LIMITS = {
     "fundEmployerRunway": 15000000,
     "fundWorkerBond": 15000000,
     "topUpRunway": 15000000,
+    "releaseUnactivatableOffer": 30000000,
+    "cancelUnfundedAgreement": 30000000,
     "claimRecurringPay": 12000000,
     "submitMilestone": 15000000,
     "approveMilestone": 15000000,
     "rejectMilestone": 15000000,
+    "resubmitMilestone": 15000000,
     "autoApproveMilestone": 15000000,
+    "raiseDispute": 15000000,
+    "resolveDispute": 20000000,
+    "cancelMilestone": 20000000,
+    "flagWorkerDefault": 20000000,
     "depositRevenue": 15000000,
     "requestTerminate": 15000000,
+    "consentTerminationCancel": 10000000,
+    "cancelTermination": 15000000,
     "finalizeTerminate": 15000000,
+    "proposeMutualTermination": 15000000,
+    "confirmMutualTermination": 20000000,
     "withdrawClaimable": 10000000,
     "setProtocolFeeBps": 10000000,
     "setReferralShareBps": 10000000,
     "setTreasury": 10000000,
     "setMinUptimeScore": 10000000,
     "setRiskParams": 15000000,
+    "setMaxRevisionRounds": 10000000,
+    "setFundingWindowSeconds": 10000000,
+    "setWorkerDefaultGraceSeconds": 10000000,
+    "setMaxNoticeSeconds": 10000000,
+    "setTerminationCancelRequiresConsent": 10000000,
     "setPaused": 10000000,
     "setOwner": 10000000,
+    "migrateLegacyAgreements": 100000000,
+    "addArbitrator": 10000000,
+    "removeArbitrator": 10000000,
+    "addAllowedToken": 10000000,
+    "removeAllowedToken": 10000000,
 }
@@ -19,22 +19,43 @@ MUTABLE_ENDPOINTS = [
     "fundEmployerRunway",
     "fundWorkerBond",
     "topUpRunway",
+    "releaseUnactivatableOffer",
+    "cancelUnfundedAgreement",
     "claimRecurringPay",
     "submitMilestone",
     "approveMilestone",
     "rejectMilestone",
+    "resubmitMilestone",
     "autoApproveMilestone",
+    "raiseDispute",
+    "resolveDispute",
+    "cancelMilestone",
+    "flagWorkerDefault",
     "depositRevenue",
     "requestTerminate",
+    "consentTerminationCancel",
+    "cancelTermination",
     "finalizeTerminate",
+    "proposeMutualTermination",
+    "confirmMutualTermination",
     "withdrawClaimable",
     "setProtocolFeeBps",
     "setReferralShareBps",
     "setTreasury",
     "setMinUptimeScore",
     "setRiskParams",
+    "setMaxRevisionRounds",
+    "setFundingWindowSeconds",
+    "setWorkerDefaultGraceSeconds",
+    "setMaxNoticeSeconds",
+    "setTerminationCancelRequiresConsent",
     "setPaused",
     "setOwner",
+    "migrateLegacyAgreements",
+    "addArbitrator",
+    "removeArbitrator",
+    "addAllowedToken",
+    "removeAllowedToken",
 ]
 
 
@@ -35,14 +35,29 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn get_accepted_offer<Arg0: ProxyArg<u64>>(
+    pub fn get_accepted_offer_for_application<Arg0: ProxyArg<u64>, Arg1: ProxyArg<u64>>(
         self,
         job_id: Arg0,
+        application_id: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<AcceptedOfferSummary<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAcceptedOffer")
+            .raw_call("getAcceptedOfferForApplication")
             .argument(&job_id)
+            .argument(&application_id)
+            .original_result()
+    }
+
+    pub fn reopen_matched_job<Arg0: ProxyArg<u64>, Arg1: ProxyArg<u64>>(
+        self,
+        job_id: Arg0,
+        offer_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reopenMatchedJob")
+            .argument(&job_id)
+            .argument(&offer_id)
             .original_result()
     }
 }
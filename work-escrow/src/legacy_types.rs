@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared_types::{AgreementStatus, MilestoneState, RecurringTermsEscrow, RevenueShareTermsEscrow};
+
+// Storage layouts written before ESDT payment tokens, negotiated termination
+// terms, funding deadlines and revision rounds were added.
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyAgreement<M: ManagedTypeApi> {
+    pub id: u64,
+    pub job_id: u64,
+    pub offer_id: u64,
+    pub employer: ManagedAddress<M>,
+    pub worker: ManagedAddress<M>,
+    pub referrer: ManagedAddress<M>,
+    pub status: AgreementStatus,
+    pub created_at: u64,
+    pub activated_at: u64,
+    pub notice_start_ts: u64,
+    pub notice_end_ts: u64,
+    pub requested_by_side: u8,
+    pub default_side: u8,
+    pub terms: LegacyAgreementTerms<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyAgreementTerms<M: ManagedTypeApi> {
+    pub recurring: RecurringTermsEscrow<M>,
+    pub revenue_share: RevenueShareTermsEscrow,
+    pub employer_bond_required: BigUint<M>,
+    pub worker_bond_required: BigUint<M>,
+    pub milestone_count: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyMilestone<M: ManagedTypeApi> {
+    pub id: u64,
+    pub agreement_id: u64,
+    pub amount: BigUint<M>,
+    pub due_ts: u64,
+    pub review_timeout_seconds: u64,
+    pub metadata_uri: ManagedBuffer<M>,
+    pub state: MilestoneState,
+    pub submitted_at: u64,
+    pub review_deadline: u64,
+    pub proof_uri: ManagedBuffer<M>,
+    pub reason_uri: ManagedBuffer<M>,
+    pub settlement_mode: u8,
+    pub paid_at: u64,
+}
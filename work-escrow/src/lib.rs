@@ -5,14 +5,16 @@ multiversx_sc::derive_imports!();
 
 mod bond_registry_proxy;
 mod job_board_proxy;
+mod legacy_types;
 mod uptime_proxy;
 
 use bond_registry_proxy::BondRegistryProxy;
 use job_board_proxy::JobBoardProxy;
+use legacy_types::{LegacyAgreement, LegacyMilestone};
 use shared_types::{
     AcceptedOfferSummary, Agreement, AgreementFinancials, AgreementStatus, AgreementTerms,
-    EscrowConfig, FundingState, Milestone, MilestoneSettlementMode, MilestoneState,
-    ProtocolStats, RecurringTermsEscrow, ReputationReason, ReputationSnapshot,
+    Dispute, EscrowConfig, FundingState, Milestone, MilestoneRevision, MilestoneSettlementMode,
+    MilestoneState, MutualTerminationProposal, OfferTerms, ProtocolStats, RecurringTermsEscrow, ReputationReason, ReputationSnapshot,
     RevenueShareTermsEscrow, TerminationReason, TerminationSide, BPS_DENOMINATOR,
     MAX_PROOF_URI_LEN, MAX_REASON_URI_LEN, SCORE_MAX,
 };
@@ -32,15 +34,30 @@ pub const ERR_INSUFFICIENT_RUNWAY: &str = "ERR_INSUFFICIENT_RUNWAY";
 pub const ERR_MILESTONE_STATE: &str = "ERR_MILESTONE_STATE";
 pub const ERR_TIMEOUT_NOT_REACHED: &str = "ERR_TIMEOUT_NOT_REACHED";
 pub const ERR_NOTHING_TO_WITHDRAW: &str = "ERR_NOTHING_TO_WITHDRAW";
+pub const ERR_INVALID_TOKEN: &str = "ERR_INVALID_TOKEN";
+pub const ERR_REVISION_LIMIT: &str = "ERR_REVISION_LIMIT";
+pub const ERR_PROPOSAL_MISMATCH: &str = "ERR_PROPOSAL_MISMATCH";
+pub const ERR_MIGRATION_PENDING: &str = "ERR_MIGRATION_PENDING";
+pub const ERR_OFFER_RELEASED: &str = "ERR_OFFER_RELEASED";
 
 const EMPLOYER_SIDE: u8 = 1;
 const WORKER_SIDE: u8 = 2;
 
+const DEFAULT_MAX_REVISION_ROUNDS: u64 = 3;
+const DEFAULT_FUNDING_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_WORKER_DEFAULT_GRACE_SECONDS: u64 = 24 * 60 * 60;
+const DEFAULT_MAX_NOTICE_SECONDS: u64 = 90 * 24 * 60 * 60;
+
+const STORAGE_LAYOUT_VERSION: u64 = 1;
+const JOB_REOPEN_GAS: u64 = 10_000_000;
+
 const SCORE_DELTA_RECURRING: i64 = 5;
 const SCORE_DELTA_MILESTONE: i64 = 3;
 const SCORE_DELTA_EMPLOYER_DEFAULT: i64 = -60;
+const SCORE_DELTA_WORKER_DEFAULT: i64 = -60;
 const SCORE_DELTA_UNILATERAL: i64 = -20;
 const SCORE_DELTA_COMPLETION: i64 = 25;
+const SCORE_DELTA_DISPUTE_LOST: i64 = -15;
 
 #[multiversx_sc::contract]
 pub trait WorkEscrow {
@@ -97,31 +114,72 @@ pub trait WorkEscrow {
         self.max_milestones_per_agreement()
             .set(max_milestones_per_agreement);
         self.score_start().set(score_start);
+        self.max_revision_rounds().set(DEFAULT_MAX_REVISION_ROUNDS);
+        self.funding_window_seconds().set(DEFAULT_FUNDING_WINDOW_SECONDS);
+        self.worker_default_grace_seconds()
+            .set(DEFAULT_WORKER_DEFAULT_GRACE_SECONDS);
+        self.max_notice_seconds()
+            .set(core::cmp::max(DEFAULT_MAX_NOTICE_SECONDS, default_notice_seconds));
 
         self.agreement_count().set(0u64);
         self.active_agreement_count().set(0u64);
         self.completed_agreement_count().set(0u64);
         self.terminated_agreement_count().set(0u64);
-        self.total_gross_payouts().set(BigUint::zero());
-        self.total_protocol_fees().set(BigUint::zero());
-        self.total_revenue_deposited().set(BigUint::zero());
+        self.allowed_tokens().insert(EgldOrEsdtTokenIdentifier::egld());
+        self.storage_layout_version().set(STORAGE_LAYOUT_VERSION);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        if self.max_revision_rounds().is_empty() {
+            self.max_revision_rounds().set(DEFAULT_MAX_REVISION_ROUNDS);
+        }
+        if self.funding_window_seconds().is_empty() {
+            self.funding_window_seconds().set(DEFAULT_FUNDING_WINDOW_SECONDS);
+        }
+        if self.worker_default_grace_seconds().is_empty() {
+            self.worker_default_grace_seconds()
+                .set(DEFAULT_WORKER_DEFAULT_GRACE_SECONDS);
+        }
+        if self.max_notice_seconds().is_empty() {
+            self.max_notice_seconds().set(core::cmp::max(
+                DEFAULT_MAX_NOTICE_SECONDS,
+                self.default_notice_seconds().get(),
+            ));
+        }
+
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+
+        // Agreements stored before the current layout are rewritten by migrateLegacyAgreements.
+        // EGLD is only seeded once so a later removeAllowedToken survives upgrades.
+        if self.storage_layout_version().get() < STORAGE_LAYOUT_VERSION {
+            self.legacy_agreement_watermark()
+                .set(self.agreement_count().get());
+            self.allowed_tokens().insert(egld.clone());
+            self.storage_layout_version().set(STORAGE_LAYOUT_VERSION);
+        }
+
+        // Totals were untyped before ESDT support, so everything recorded so far is EGLD.
+        self.total_gross_payouts(&egld)
+            .update(|v| *v += self.legacy_total_gross_payouts().take());
+        self.total_protocol_fees(&egld)
+            .update(|v| *v += self.legacy_total_protocol_fees().take());
+        self.total_revenue_deposited(&egld)
+            .update(|v| *v += self.legacy_total_revenue_deposited().take());
+    }
 
     #[endpoint(activateAgreement)]
     fn activate_agreement(
         &self,
         job_id: u64,
-        offer_id: u64,
+        application_id: u64,
         referrer: OptionalValue<ManagedAddress>,
     ) -> u64 {
         self.require_not_paused();
-        require!(!self.offer_consumed(job_id, offer_id).get(), ERR_OFFER_CONSUMED);
 
-        let accepted = self.fetch_accepted_offer(job_id);
-        require!(accepted.offer_id == offer_id, ERR_OFFER_NOT_ACCEPTED);
+        let accepted = self.fetch_accepted_offer(job_id, application_id);
+        let offer_id = accepted.offer_id;
+        self.require_offer_unspent(job_id, offer_id);
 
         let caller = self.blockchain().get_caller();
         require!(
@@ -160,6 +218,7 @@ pub trait WorkEscrow {
         };
 
         let terms = AgreementTerms {
+            payment_token: accepted.terms.payment_token.clone(),
             recurring,
             revenue_share: RevenueShareTermsEscrow {
                 profit_share_bps: accepted.terms.revenue_share.profit_share_bps,
@@ -168,6 +227,8 @@ pub trait WorkEscrow {
             },
             employer_bond_required,
             worker_bond_required,
+            notice_seconds: accepted.terms.notice_seconds,
+            termination_penalty_bps: accepted.terms.termination_penalty_bps,
             milestone_count: accepted.terms.milestones.len() as u64,
         };
 
@@ -185,6 +246,7 @@ pub trait WorkEscrow {
             referrer: resolved_referrer,
             status: AgreementStatus::PendingFunding,
             created_at: now,
+            funding_deadline_ts: now + self.funding_window_seconds().get(),
             activated_at: 0,
             notice_start_ts: 0,
             notice_end_ts: 0,
@@ -222,6 +284,7 @@ pub trait WorkEscrow {
                 reason_uri: ManagedBuffer::new(),
                 settlement_mode: 0,
                 paid_at: 0,
+                revision_count: 0,
             });
         }
 
@@ -241,7 +304,7 @@ pub trait WorkEscrow {
     }
 
     #[endpoint(fundEmployerRunway)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn fund_employer_runway(&self, agreement_id: u64) {
         self.require_not_paused();
         let mut agreement = self.require_agreement(agreement_id);
@@ -253,8 +316,7 @@ pub trait WorkEscrow {
             ERR_INVALID_STATE
         );
 
-        let payment = self.call_value().egld_value().clone_value();
-        require!(payment > 0u64, ERR_INVALID_AMOUNT);
+        let payment = self.require_agreement_payment(&agreement);
 
         let mut funding = self.agreement_financials(agreement_id).get();
         let mut remaining = payment.clone();
@@ -285,7 +347,7 @@ pub trait WorkEscrow {
     }
 
     #[endpoint(fundWorkerBond)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn fund_worker_bond(&self, agreement_id: u64) {
         self.require_not_paused();
         let mut agreement = self.require_agreement(agreement_id);
@@ -297,8 +359,7 @@ pub trait WorkEscrow {
             ERR_INVALID_STATE
         );
 
-        let payment = self.call_value().egld_value().clone_value();
-        require!(payment > 0u64, ERR_INVALID_AMOUNT);
+        let payment = self.require_agreement_payment(&agreement);
 
         let mut funding = self.agreement_financials(agreement_id).get();
         funding.worker_bond_locked += &payment;
@@ -315,7 +376,7 @@ pub trait WorkEscrow {
     }
 
     #[endpoint(topUpRunway)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn top_up_runway(&self, agreement_id: u64) {
         self.require_not_paused();
         let agreement = self.require_agreement(agreement_id);
@@ -327,8 +388,7 @@ pub trait WorkEscrow {
             ERR_INVALID_STATE
         );
 
-        let payment = self.call_value().egld_value().clone_value();
-        require!(payment > 0u64, ERR_INVALID_AMOUNT);
+        let payment = self.require_agreement_payment(&agreement);
 
         let mut funding = self.agreement_financials(agreement_id).get();
         funding.runway_balance += &payment;
@@ -343,13 +403,84 @@ pub trait WorkEscrow {
         );
     }
 
+    #[endpoint(releaseUnactivatableOffer)]
+    fn release_unactivatable_offer(&self, job_id: u64, application_id: u64) {
+        self.require_not_paused();
+        let accepted = self.fetch_accepted_offer(job_id, application_id);
+        let offer_id = accepted.offer_id;
+        self.require_offer_unspent(job_id, offer_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == accepted.employer || caller == accepted.worker,
+            ERR_UNAUTHORIZED
+        );
+        // Only offers the current risk params or token allowlist reject can be released.
+        require!(self.offer_terms_error(&accepted.terms).is_some(), ERR_INVALID_STATE);
+
+        self.notify_job_reopened(job_id, offer_id);
+        self.accepted_offer_released_event(
+            job_id,
+            offer_id,
+            &caller,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    #[endpoint(cancelUnfundedAgreement)]
+    fn cancel_unfunded_agreement(&self, agreement_id: u64) {
+        self.require_not_paused();
+        let mut agreement = self.require_agreement(agreement_id);
+        require!(
+            agreement.status == AgreementStatus::PendingFunding,
+            ERR_INVALID_STATE
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(now > agreement.funding_deadline_ts, ERR_TIMEOUT_NOT_REACHED);
+
+        let mut funding = self.agreement_financials(agreement_id).get();
+        let employer_refund = &funding.employer_bond_locked + &funding.runway_balance;
+        let worker_refund = funding.worker_bond_locked.clone();
+
+        let token = &agreement.terms.payment_token;
+        self.add_claimable(&agreement.employer, token, &employer_refund);
+        self.add_claimable(&agreement.worker, token, &worker_refund);
+
+        funding.employer_bond_locked = BigUint::zero();
+        funding.worker_bond_locked = BigUint::zero();
+        funding.runway_balance = BigUint::zero();
+        self.agreement_financials(agreement_id).set(funding);
+
+        agreement.status = AgreementStatus::Cancelled;
+        self.agreements(agreement_id).set(agreement.clone());
+
+        // The accepted offer never backed a live agreement; the board voids it and reopens the job.
+        self.offer_consumed(agreement.job_id, agreement.offer_id).clear();
+        self.agreement_by_offer(agreement.job_id, agreement.offer_id).clear();
+        self.notify_job_reopened(agreement.job_id, agreement.offer_id);
+
+        self.agreement_cancelled_event(
+            agreement_id,
+            &self.blockchain().get_caller(),
+            employer_refund,
+            worker_refund,
+            now,
+        );
+    }
+
     #[endpoint(claimRecurringPay)]
     fn claim_recurring_pay(&self, agreement_id: u64) {
         self.require_not_paused();
         let mut agreement = self.require_agreement(agreement_id);
         let caller = self.blockchain().get_caller();
         require!(caller == agreement.worker, ERR_UNAUTHORIZED);
-        require!(agreement.status == AgreementStatus::Active, ERR_INVALID_STATE);
+        let in_notice =
+            agreement.status == AgreementStatus::NoticePeriod && agreement.default_side == 0;
+        require!(
+            agreement.status == AgreementStatus::Active || in_notice,
+            ERR_INVALID_STATE
+        );
 
         require!(
             agreement.terms.recurring.total_periods > 0
@@ -361,43 +492,36 @@ pub trait WorkEscrow {
             ERR_INVALID_STATE
         );
 
+        // Work performed during notice accrues only until the notice ends.
         let now = self.blockchain().get_block_timestamp();
-        require!(now >= agreement.terms.recurring.next_pay_ts, ERR_INVALID_DEADLINE);
+        let cutoff_ts = if in_notice {
+            core::cmp::min(now, agreement.notice_end_ts)
+        } else {
+            now
+        };
+        require!(cutoff_ts >= agreement.terms.recurring.next_pay_ts, ERR_INVALID_DEADLINE);
 
-        let gross = agreement.terms.recurring.amount_per_period.clone();
+        let due_periods = self.elapsed_recurring_periods(&agreement, cutoff_ts);
         let mut funding = self.agreement_financials(agreement_id).get();
+        let periods = core::cmp::min(
+            due_periods,
+            self.affordable_recurring_periods(&agreement, &funding),
+        );
 
-        if funding.runway_balance < gross {
+        if periods == 0 {
             self.handle_employer_default(agreement_id, &mut agreement);
             return;
         }
 
-        funding.runway_balance -= &gross;
-        agreement.terms.recurring.paid_periods += 1;
-        agreement.terms.recurring.next_pay_ts += agreement.terms.recurring.period_seconds;
-
-        let (protocol_fee, _referral_fee, worker_net) =
-            self.credit_worker_payout(&agreement, &gross, agreement_id);
-
+        self.pay_recurring_periods(agreement_id, &mut agreement, &mut funding, periods);
         self.agreement_financials(agreement_id).set(funding.clone());
         self.agreements(agreement_id).set(agreement.clone());
 
-        self.record_agreement_totals(agreement_id, &gross, &protocol_fee);
-        self.apply_reputation_delta(
-            &agreement.employer,
-            SCORE_DELTA_RECURRING,
-            ReputationReason::OnTimeRecurringPayment,
-            agreement_id,
-        );
-
-        self.pay_claimed_event(
-            agreement_id,
-            agreement.terms.recurring.paid_periods,
-            gross,
-            protocol_fee,
-            worker_net,
-            now,
-        );
+        // Runway covered only part of the elapsed periods.
+        if periods < due_periods {
+            self.handle_employer_default(agreement_id, &mut agreement);
+            return;
+        }
 
         self.try_complete(agreement_id, &mut agreement, &mut funding);
     }
@@ -487,6 +611,15 @@ pub trait WorkEscrow {
             ERR_TIMEOUT_NOT_REACHED
         );
 
+        self.milestone_revisions(agreement_id, milestone_id)
+            .push(&MilestoneRevision {
+                round: milestone.revision_count,
+                proof_uri: milestone.proof_uri.clone(),
+                reason_uri: reason_uri.clone(),
+                submitted_at: milestone.submitted_at,
+                rejected_at: self.blockchain().get_block_timestamp(),
+            });
+
         milestone.state = MilestoneState::Rejected;
         milestone.reason_uri = reason_uri;
         self.milestones(agreement_id, milestone_id).set(milestone);
@@ -499,6 +632,40 @@ pub trait WorkEscrow {
         );
     }
 
+    #[endpoint(resubmitMilestone)]
+    fn resubmit_milestone(&self, agreement_id: u64, milestone_id: u64, proof_uri: ManagedBuffer) {
+        self.require_not_paused();
+        let agreement = self.require_agreement(agreement_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == agreement.worker, ERR_UNAUTHORIZED);
+        require!(agreement.status == AgreementStatus::Active, ERR_INVALID_STATE);
+        require!(proof_uri.len() <= MAX_PROOF_URI_LEN, ERR_INVALID_AMOUNT);
+
+        let mut milestone = self.require_milestone(agreement_id, milestone_id);
+        require!(milestone.state == MilestoneState::Rejected, ERR_MILESTONE_STATE);
+        require!(
+            milestone.revision_count < self.max_revision_rounds().get(),
+            ERR_REVISION_LIMIT
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        milestone.state = MilestoneState::Submitted;
+        milestone.revision_count += 1;
+        milestone.submitted_at = now;
+        milestone.review_deadline = now + milestone.review_timeout_seconds;
+        milestone.proof_uri = proof_uri;
+        milestone.reason_uri = ManagedBuffer::new();
+
+        self.milestones(agreement_id, milestone_id).set(milestone.clone());
+        self.milestone_resubmitted_event(
+            agreement_id,
+            milestone_id,
+            &caller,
+            milestone.revision_count,
+            now,
+        );
+    }
+
     #[endpoint(autoApproveMilestone)]
     fn auto_approve_milestone(&self, agreement_id: u64, milestone_id: u64) {
         self.require_not_paused();
@@ -547,8 +714,206 @@ pub trait WorkEscrow {
         self.try_complete(agreement_id, &mut agreement, &mut funding);
     }
 
+    #[endpoint(raiseDispute)]
+    fn raise_dispute(&self, agreement_id: u64, milestone_id: u64, reason_uri: ManagedBuffer) {
+        self.require_not_paused();
+        let agreement = self.require_agreement(agreement_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == agreement.worker, ERR_UNAUTHORIZED);
+        require!(
+            agreement.status == AgreementStatus::Active
+                || agreement.status == AgreementStatus::NoticePeriod,
+            ERR_INVALID_STATE
+        );
+        require!(reason_uri.len() <= MAX_REASON_URI_LEN, ERR_INVALID_AMOUNT);
+
+        let mut milestone = self.require_milestone(agreement_id, milestone_id);
+        require!(milestone.state == MilestoneState::Rejected, ERR_MILESTONE_STATE);
+
+        let now = self.blockchain().get_block_timestamp();
+        milestone.state = MilestoneState::Disputed;
+        self.milestones(agreement_id, milestone_id).set(milestone);
+
+        self.disputes(agreement_id, milestone_id).set(Dispute {
+            agreement_id,
+            milestone_id,
+            reason_uri,
+            raised_at: now,
+            resolved_at: 0,
+            arbitrator: ManagedAddress::zero(),
+            worker_share_bps: 0,
+        });
+
+        self.dispute_raised_event(agreement_id, milestone_id, &caller, now);
+    }
+
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, agreement_id: u64, milestone_id: u64, worker_share_bps: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        require!(self.arbitrators().contains(&caller), ERR_UNAUTHORIZED);
+        require!(worker_share_bps <= BPS_DENOMINATOR, ERR_INVALID_BPS);
+
+        let mut agreement = self.require_agreement(agreement_id);
+        // Termination holds disputed amounts back, so disputes stay resolvable afterwards.
+        require!(
+            agreement.status == AgreementStatus::Active
+                || agreement.status == AgreementStatus::NoticePeriod
+                || agreement.status == AgreementStatus::Terminated,
+            ERR_INVALID_STATE
+        );
+
+        let mut milestone = self.require_milestone(agreement_id, milestone_id);
+        require!(milestone.state == MilestoneState::Disputed, ERR_MILESTONE_STATE);
+
+        let mut funding = self.agreement_financials(agreement_id).get();
+        let amount = if agreement.status == AgreementStatus::Terminated {
+            // Only the held amount is left; the recurring reserve no longer applies.
+            self.min_biguint(&milestone.amount, &funding.runway_balance)
+        } else {
+            self.require_milestone_runway(&funding, &milestone.amount);
+            milestone.amount.clone()
+        };
+
+        let now = self.blockchain().get_block_timestamp();
+        let worker_gross = self.mul_bps(&amount, worker_share_bps);
+        let employer_share = &amount - &worker_gross;
+
+        funding.runway_balance -= &amount;
+        milestone.state = MilestoneState::Paid;
+        milestone.settlement_mode = MilestoneSettlementMode::Arbitrated as u8;
+        milestone.paid_at = now;
+        self.milestones(agreement_id, milestone_id).set(milestone);
+
+        let mut protocol_fee = BigUint::zero();
+        let mut worker_net = BigUint::zero();
+        if worker_gross > 0u64 {
+            let (fee, _, net) = self.credit_worker_payout(&agreement, &worker_gross, agreement_id);
+            self.record_agreement_totals(agreement_id, &worker_gross, &fee);
+            protocol_fee = fee;
+            worker_net = net;
+        }
+        self.add_claimable(&agreement.employer, &agreement.terms.payment_token, &employer_share);
+        self.agreement_financials(agreement_id).set(funding.clone());
+
+        let mut dispute = self.disputes(agreement_id, milestone_id).get();
+        dispute.resolved_at = now;
+        dispute.arbitrator = caller.clone();
+        dispute.worker_share_bps = worker_share_bps;
+        self.disputes(agreement_id, milestone_id).set(dispute);
+
+        // A 50/50 split has no losing side.
+        let half = BPS_DENOMINATOR / 2;
+        if worker_share_bps < half {
+            self.apply_reputation_delta(
+                &agreement.worker,
+                SCORE_DELTA_DISPUTE_LOST,
+                ReputationReason::DisputeLost,
+                agreement_id,
+            );
+        } else if worker_share_bps > half {
+            self.apply_reputation_delta(
+                &agreement.employer,
+                SCORE_DELTA_DISPUTE_LOST,
+                ReputationReason::DisputeLost,
+                agreement_id,
+            );
+        }
+
+        self.dispute_resolved_event(
+            agreement_id,
+            milestone_id,
+            &caller,
+            worker_share_bps,
+            worker_gross.clone(),
+            employer_share,
+            now,
+        );
+        self.milestone_settled_event(
+            agreement_id,
+            milestone_id,
+            MilestoneSettlementMode::Arbitrated as u8,
+            worker_gross,
+            protocol_fee,
+            worker_net,
+            now,
+        );
+
+        self.try_complete(agreement_id, &mut agreement, &mut funding);
+    }
+
+    #[endpoint(cancelMilestone)]
+    fn cancel_milestone(&self, agreement_id: u64, milestone_id: u64) {
+        self.require_not_paused();
+        let mut agreement = self.require_agreement(agreement_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == agreement.employer, ERR_UNAUTHORIZED);
+        require!(agreement.status == AgreementStatus::Active, ERR_INVALID_STATE);
+
+        let mut milestone = self.require_milestone(agreement_id, milestone_id);
+        require!(milestone.state == MilestoneState::Rejected, ERR_MILESTONE_STATE);
+        require!(
+            milestone.revision_count >= self.max_revision_rounds().get(),
+            ERR_MILESTONE_STATE
+        );
+
+        // The worker keeps one review window after the final rejection to raise a dispute.
+        let revisions = self.milestone_revisions(agreement_id, milestone_id);
+        let rejected_at = if revisions.is_empty() {
+            0
+        } else {
+            revisions.get(revisions.len()).rejected_at
+        };
+        let now = self.blockchain().get_block_timestamp();
+        require!(
+            now > rejected_at + milestone.review_timeout_seconds,
+            ERR_TIMEOUT_NOT_REACHED
+        );
+
+        // The amount stays in the runway and returns to the employer when the agreement ends.
+        milestone.state = MilestoneState::Cancelled;
+        self.milestones(agreement_id, milestone_id).set(milestone.clone());
+
+        self.milestone_cancelled_event(agreement_id, milestone_id, &caller, milestone.amount, now);
+
+        let mut funding = self.agreement_financials(agreement_id).get();
+        self.try_complete(agreement_id, &mut agreement, &mut funding);
+    }
+
+    #[endpoint(flagWorkerDefault)]
+    fn flag_worker_default(&self, agreement_id: u64, milestone_id: u64) {
+        self.require_not_paused();
+        let mut agreement = self.require_agreement(agreement_id);
+        require!(agreement.status == AgreementStatus::Active, ERR_INVALID_STATE);
+
+        let milestone = self.require_milestone(agreement_id, milestone_id);
+        require!(milestone.state == MilestoneState::Open, ERR_MILESTONE_STATE);
+        require!(milestone.due_ts > 0, ERR_INVALID_DEADLINE);
+        require!(
+            self.blockchain().get_block_timestamp()
+                > milestone
+                    .due_ts
+                    .saturating_add(self.worker_default_grace_seconds().get()),
+            ERR_TIMEOUT_NOT_REACHED
+        );
+
+        // Periods that elapsed before the default are still owed to the worker.
+        let elapsed = self.elapsed_recurring_periods(&agreement, self.blockchain().get_block_timestamp());
+        if elapsed > 0 {
+            let mut funding = self.agreement_financials(agreement_id).get();
+            let periods = core::cmp::min(elapsed, self.affordable_recurring_periods(&agreement, &funding));
+            if periods > 0 {
+                self.pay_recurring_periods(agreement_id, &mut agreement, &mut funding, periods);
+                self.agreement_financials(agreement_id).set(funding);
+            }
+        }
+
+        self.handle_worker_default(agreement_id, &mut agreement);
+        self.finalize_terminate(agreement_id);
+    }
+
     #[endpoint(depositRevenue)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn deposit_revenue(&self, agreement_id: u64) {
         self.require_not_paused();
         let agreement = self.require_agreement(agreement_id);
@@ -558,9 +923,9 @@ pub trait WorkEscrow {
             ERR_INVALID_STATE
         );
 
-        let gross = self.call_value().egld_value().clone_value();
-        require!(gross > 0u64, ERR_INVALID_AMOUNT);
+        let gross = self.require_agreement_payment(&agreement);
 
+        let token = &agreement.terms.payment_token;
         let protocol_fee = self.mul_bps(
             &gross,
             agreement.terms.revenue_share.protocol_fee_bps_snapshot,
@@ -578,16 +943,16 @@ pub trait WorkEscrow {
                 &protocol_fee,
                 agreement.terms.revenue_share.referral_share_bps_snapshot,
             );
-            self.add_claimable(&agreement.referrer, &referral_fee);
+            self.add_claimable(&agreement.referrer, token, &referral_fee);
         }
         let treasury_fee = &protocol_fee - &referral_fee;
 
-        self.add_claimable(&agreement.worker, &worker_share);
-        self.add_claimable(&agreement.employer, &employer_share);
-        self.add_claimable(&self.treasury().get(), &treasury_fee);
+        self.add_claimable(&agreement.worker, token, &worker_share);
+        self.add_claimable(&agreement.employer, token, &employer_share);
+        self.add_claimable(&self.treasury().get(), token, &treasury_fee);
 
-        self.total_revenue_deposited().update(|v| *v += &gross);
-        self.total_protocol_fees().update(|v| *v += &protocol_fee);
+        self.total_revenue_deposited(token).update(|v| *v += &gross);
+        self.total_protocol_fees(token).update(|v| *v += &protocol_fee);
 
         self.revenue_deposited_event(
             agreement_id,
@@ -614,7 +979,9 @@ pub trait WorkEscrow {
 
         agreement.status = AgreementStatus::NoticePeriod;
         agreement.notice_start_ts = self.blockchain().get_block_timestamp();
-        agreement.notice_end_ts = agreement.notice_start_ts + self.default_notice_seconds().get();
+        agreement.notice_end_ts = agreement
+            .notice_start_ts
+            .saturating_add(agreement.terms.notice_seconds);
         agreement.requested_by_side = if matches!(side, TerminationSide::Employer) {
             EMPLOYER_SIDE
         } else {
@@ -637,6 +1004,54 @@ pub trait WorkEscrow {
         );
     }
 
+    #[endpoint(consentTerminationCancel)]
+    fn consent_termination_cancel(&self, agreement_id: u64) {
+        self.require_not_paused();
+        let agreement = self.require_agreement(agreement_id);
+        self.require_cancellable_termination(&agreement);
+
+        let caller = self.blockchain().get_caller();
+        if agreement.requested_by_side == EMPLOYER_SIDE {
+            require!(caller == agreement.worker, ERR_UNAUTHORIZED);
+        } else {
+            require!(caller == agreement.employer, ERR_UNAUTHORIZED);
+        }
+
+        self.termination_cancel_consent(agreement_id).set(true);
+    }
+
+    #[endpoint(cancelTermination)]
+    fn cancel_termination(&self, agreement_id: u64) {
+        self.require_not_paused();
+        let mut agreement = self.require_agreement(agreement_id);
+        self.require_cancellable_termination(&agreement);
+
+        let caller = self.blockchain().get_caller();
+        if agreement.requested_by_side == EMPLOYER_SIDE {
+            require!(caller == agreement.employer, ERR_UNAUTHORIZED);
+        } else {
+            require!(caller == agreement.worker, ERR_UNAUTHORIZED);
+        }
+        if self.termination_cancel_requires_consent().get() {
+            require!(self.termination_cancel_consent(agreement_id).get(), ERR_UNAUTHORIZED);
+        }
+
+        let side = agreement.requested_by_side;
+        agreement.status = AgreementStatus::Active;
+        agreement.notice_start_ts = 0;
+        agreement.notice_end_ts = 0;
+        agreement.requested_by_side = 0;
+        self.agreements(agreement_id).set(agreement);
+        self.termination_cancel_consent(agreement_id).clear();
+        self.active_agreement_count().update(|v| *v += 1);
+
+        self.termination_cancelled_event(
+            agreement_id,
+            side,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
     #[endpoint(finalizeTerminate)]
     fn finalize_terminate(&self, agreement_id: u64) {
         self.require_not_paused();
@@ -654,6 +1069,10 @@ pub trait WorkEscrow {
         }
 
         let mut funding = self.agreement_financials(agreement_id).get();
+        if agreement.default_side != WORKER_SIDE {
+            self.settle_notice_pay(agreement_id, &mut agreement, &mut funding);
+        }
+        let disputed_hold = self.settle_pending_milestones(agreement_id, &agreement, &mut funding);
 
         let penalty_from_side = if agreement.default_side != 0 {
             agreement.default_side
@@ -667,24 +1086,29 @@ pub trait WorkEscrow {
             (&mut funding.worker_bond_locked, &agreement.employer)
         };
 
-        let penalty = self.mul_bps(&penalty_source.clone(), self.termination_penalty_bps().get());
+        let penalty = self.mul_bps(&penalty_source.clone(), agreement.terms.termination_penalty_bps);
         if penalty > 0u64 {
             *penalty_source -= &penalty;
-            self.add_claimable(counterparty, &penalty);
+            self.add_claimable(counterparty, &agreement.terms.payment_token, &penalty);
         }
 
         let employer_refund = funding.employer_bond_locked.clone();
         let worker_refund = funding.worker_bond_locked.clone();
 
         if employer_refund > 0u64 {
-            self.add_claimable(&agreement.employer, &employer_refund);
+            self.add_claimable(&agreement.employer, &agreement.terms.payment_token, &employer_refund);
         }
         if worker_refund > 0u64 {
-            self.add_claimable(&agreement.worker, &worker_refund);
+            self.add_claimable(&agreement.worker, &agreement.terms.payment_token, &worker_refund);
         }
 
         funding.employer_bond_locked = BigUint::zero();
         funding.worker_bond_locked = BigUint::zero();
+
+        // Disputed amounts stay escrowed until the arbitrator resolves them.
+        funding.runway_balance -= &disputed_hold;
+        let runway_refund = self.release_runway_to_employer(&agreement, &mut funding);
+        funding.runway_balance = disputed_hold;
         self.agreement_financials(agreement_id).set(funding);
 
         let reason = if agreement.default_side == EMPLOYER_SIDE {
@@ -719,20 +1143,163 @@ pub trait WorkEscrow {
             penalty,
             employer_refund,
             worker_refund,
+            runway_refund,
             now,
         );
     }
 
-    #[endpoint(withdrawClaimable)]
-    fn withdraw_claimable(&self) {
+    #[endpoint(proposeMutualTermination)]
+    fn propose_mutual_termination(
+        &self,
+        agreement_id: u64,
+        end_ts: u64,
+        confirm_deadline_ts: u64,
+        worker_settlement: BigUint,
+    ) {
+        self.require_not_paused();
+        let agreement = self.require_agreement(agreement_id);
+        self.require_mutual_termination_state(&agreement);
+
         let caller = self.blockchain().get_caller();
-        let amount = self.claimable(&caller).get();
+        let proposer_side = if caller == agreement.employer {
+            EMPLOYER_SIDE
+        } else if caller == agreement.worker {
+            WORKER_SIDE
+        } else {
+            sc_panic!(ERR_UNAUTHORIZED)
+        };
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(
+            end_ts >= now && confirm_deadline_ts >= end_ts,
+            ERR_INVALID_DEADLINE
+        );
+
+        let funding = self.agreement_financials(agreement_id).get();
+        require!(worker_settlement <= funding.runway_balance, ERR_INSUFFICIENT_RUNWAY);
+
+        self.mutual_termination_proposal(agreement_id)
+            .set(MutualTerminationProposal {
+                proposer_side,
+                end_ts,
+                confirm_deadline_ts,
+                worker_settlement: worker_settlement.clone(),
+                proposed_at: now,
+            });
+
+        self.mutual_termination_proposed_event(
+            agreement_id,
+            proposer_side,
+            end_ts,
+            confirm_deadline_ts,
+            worker_settlement,
+            now,
+        );
+    }
+
+    #[endpoint(confirmMutualTermination)]
+    fn confirm_mutual_termination(
+        &self,
+        agreement_id: u64,
+        expected_end_ts: u64,
+        expected_confirm_deadline_ts: u64,
+        expected_worker_settlement: BigUint,
+    ) {
+        self.require_not_paused();
+        let mut agreement = self.require_agreement(agreement_id);
+        self.require_mutual_termination_state(&agreement);
+        require!(
+            !self.mutual_termination_proposal(agreement_id).is_empty(),
+            ERR_INVALID_STATE
+        );
+
+        let proposal = self.mutual_termination_proposal(agreement_id).get();
+        let caller = self.blockchain().get_caller();
+        if proposal.proposer_side == EMPLOYER_SIDE {
+            require!(caller == agreement.worker, ERR_UNAUTHORIZED);
+        } else {
+            require!(caller == agreement.employer, ERR_UNAUTHORIZED);
+        }
+
+        // The proposer may replace the proposal at any time, so the confirmer
+        // pins the exact terms they agreed to.
+        require!(
+            proposal.end_ts == expected_end_ts
+                && proposal.confirm_deadline_ts == expected_confirm_deadline_ts
+                && proposal.worker_settlement == expected_worker_settlement,
+            ERR_PROPOSAL_MISMATCH
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(now >= proposal.end_ts, ERR_TIMEOUT_NOT_REACHED);
+        require!(now <= proposal.confirm_deadline_ts, ERR_INVALID_DEADLINE);
+
+        // Recurring pay accrues up to the agreed end date; the settlement comes on top.
+        let mut funding = self.agreement_financials(agreement_id).get();
+        agreement.notice_start_ts = now;
+        agreement.notice_end_ts = proposal.end_ts;
+        self.settle_notice_pay(agreement_id, &mut agreement, &mut funding);
+        require!(
+            proposal.worker_settlement <= funding.runway_balance,
+            ERR_INSUFFICIENT_RUNWAY
+        );
+
+        if proposal.worker_settlement > 0u64 {
+            funding.runway_balance -= &proposal.worker_settlement;
+            let (protocol_fee, _, _) =
+                self.credit_worker_payout(&agreement, &proposal.worker_settlement, agreement_id);
+            self.record_agreement_totals(agreement_id, &proposal.worker_settlement, &protocol_fee);
+        }
+
+        let token = &agreement.terms.payment_token;
+        let employer_refund = funding.employer_bond_locked.clone();
+        let worker_refund = funding.worker_bond_locked.clone();
+        self.add_claimable(&agreement.employer, token, &employer_refund);
+        self.add_claimable(&agreement.worker, token, &worker_refund);
+        funding.employer_bond_locked = BigUint::zero();
+        funding.worker_bond_locked = BigUint::zero();
+
+        // The agreed settlement supersedes any pending milestone obligations.
+        let runway_refund = self.release_runway_to_employer(&agreement, &mut funding);
+        self.agreement_financials(agreement_id).set(funding);
+
+        if agreement.status == AgreementStatus::Active {
+            self.active_agreement_count().update(|v| {
+                if *v > 0 {
+                    *v -= 1;
+                }
+            });
+        }
+
+        agreement.status = AgreementStatus::Terminated;
+        self.agreements(agreement_id).set(agreement);
+        self.mutual_termination_proposal(agreement_id).clear();
+        self.terminated_agreement_count().update(|v| *v += 1);
+
+        self.agreement_terminated_event(
+            agreement_id,
+            TerminationReason::Mutual as u8,
+            BigUint::zero(),
+            employer_refund,
+            worker_refund,
+            runway_refund,
+            now,
+        );
+    }
+
+    #[endpoint(withdrawClaimable)]
+    fn withdraw_claimable(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        let mut amount = self.claimable(&caller, &token).get();
+        if token.is_egld() {
+            amount += self.legacy_claimable(&caller).take();
+        }
         require!(amount > 0u64, ERR_NOTHING_TO_WITHDRAW);
 
-        self.claimable(&caller).set(BigUint::zero());
-        self.send().direct_egld(&caller, &amount);
+        self.claimable(&caller, &token).set(BigUint::zero());
+        self.send().direct(&caller, &token, 0, &amount);
 
-        self.fee_withdrawn_event(&caller, amount, self.blockchain().get_block_timestamp());
+        self.fee_withdrawn_event(&caller, &token, amount, self.blockchain().get_block_timestamp());
     }
 
     #[endpoint(setProtocolFeeBps)]
@@ -779,6 +1346,10 @@ pub trait WorkEscrow {
         require!(min_worker_bond > 0u64, ERR_INVALID_AMOUNT);
         require!(min_runway_periods > 0, ERR_INVALID_AMOUNT);
         require!(default_notice_seconds > 0, ERR_INVALID_AMOUNT);
+        require!(
+            default_notice_seconds <= self.max_notice_seconds().get(),
+            ERR_INVALID_DEADLINE
+        );
         require!(termination_penalty_bps <= BPS_DENOMINATOR, ERR_INVALID_BPS);
         require!(milestone_review_timeout_seconds > 0, ERR_INVALID_AMOUNT);
         require!(max_milestones_per_agreement > 0, ERR_INVALID_AMOUNT);
@@ -795,6 +1366,38 @@ pub trait WorkEscrow {
         self.score_start().set(score_start);
     }
 
+    #[endpoint(setMaxRevisionRounds)]
+    fn set_max_revision_rounds(&self, value: u64) {
+        self.require_owner();
+        self.max_revision_rounds().set(value);
+    }
+
+    #[endpoint(setFundingWindowSeconds)]
+    fn set_funding_window_seconds(&self, value: u64) {
+        self.require_owner();
+        require!(value > 0, ERR_INVALID_AMOUNT);
+        self.funding_window_seconds().set(value);
+    }
+
+    #[endpoint(setWorkerDefaultGraceSeconds)]
+    fn set_worker_default_grace_seconds(&self, value: u64) {
+        self.require_owner();
+        self.worker_default_grace_seconds().set(value);
+    }
+
+    #[endpoint(setMaxNoticeSeconds)]
+    fn set_max_notice_seconds(&self, value: u64) {
+        self.require_owner();
+        require!(value >= self.default_notice_seconds().get(), ERR_INVALID_DEADLINE);
+        self.max_notice_seconds().set(value);
+    }
+
+    #[endpoint(setTerminationCancelRequiresConsent)]
+    fn set_termination_cancel_requires_consent(&self, value: bool) {
+        self.require_owner();
+        self.termination_cancel_requires_consent().set(value);
+    }
+
     #[endpoint(setPaused)]
     fn set_paused(&self, paused: bool) {
         self.require_owner();
@@ -808,9 +1411,53 @@ pub trait WorkEscrow {
         self.owner().set(new_owner);
     }
 
+    #[endpoint(migrateLegacyAgreements)]
+    fn migrate_legacy_agreements(&self, max_count: u64) -> u64 {
+        self.require_owner();
+        require!(max_count > 0, ERR_INVALID_AMOUNT);
+        let watermark = self.legacy_agreement_watermark().get();
+        let from = self.legacy_migration_cursor().get() + 1;
+        let to = core::cmp::min(watermark, from + max_count - 1);
+        require!(from <= to, ERR_INVALID_STATE);
+
+        for agreement_id in from..=to {
+            self.migrate_legacy_agreement(agreement_id);
+        }
+        self.legacy_migration_cursor().set(to);
+
+        self.legacy_agreements_migrated_event(from, to, self.blockchain().get_block_timestamp());
+        watermark - to
+    }
+
+    #[endpoint(addArbitrator)]
+    fn add_arbitrator(&self, arbitrator: ManagedAddress) {
+        self.require_owner();
+        require!(!arbitrator.is_zero(), ERR_INVALID_AMOUNT);
+        self.arbitrators().insert(arbitrator);
+    }
+
+    #[endpoint(removeArbitrator)]
+    fn remove_arbitrator(&self, arbitrator: ManagedAddress) {
+        self.require_owner();
+        require!(self.arbitrators().swap_remove(&arbitrator), ERR_INVALID_STATE);
+    }
+
+    #[endpoint(addAllowedToken)]
+    fn add_allowed_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        self.require_owner();
+        require!(token.is_valid(), ERR_INVALID_TOKEN);
+        self.allowed_tokens().insert(token);
+    }
+
+    #[endpoint(removeAllowedToken)]
+    fn remove_allowed_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        self.require_owner();
+        require!(self.allowed_tokens().swap_remove(&token), ERR_INVALID_TOKEN);
+    }
+
     #[view(getAgreement)]
     fn get_agreement(&self, agreement_id: u64) -> OptionalValue<Agreement<Self::Api>> {
-        if self.agreements(agreement_id).is_empty() {
+        if self.agreements(agreement_id).is_empty() || self.is_legacy_agreement(agreement_id) {
             OptionalValue::None
         } else {
             OptionalValue::Some(self.agreements(agreement_id).get())
@@ -821,17 +1468,18 @@ pub trait WorkEscrow {
     fn get_agreement_financials(&self, agreement_id: u64) -> AgreementFinancials<Self::Api> {
         let agreement = self.require_agreement(agreement_id);
         let funding = self.agreement_financials(agreement_id).get();
+        let token = &agreement.terms.payment_token;
 
         AgreementFinancials {
             funding,
-            worker_claimable: self.claimable(&agreement.worker).get(),
-            employer_claimable: self.claimable(&agreement.employer).get(),
+            worker_claimable: self.claimable(&agreement.worker, token).get(),
+            employer_claimable: self.claimable(&agreement.employer, token).get(),
             referrer_claimable: if agreement.referrer.is_zero() {
                 BigUint::zero()
             } else {
-                self.claimable(&agreement.referrer).get()
+                self.claimable(&agreement.referrer, token).get()
             },
-            treasury_claimable: self.claimable(&self.treasury().get()).get(),
+            treasury_claimable: self.claimable(&self.treasury().get(), token).get(),
             total_gross_paid: self.agreement_total_gross_paid(agreement_id).get(),
             total_fees_paid: self.agreement_total_fees_paid(agreement_id).get(),
         }
@@ -839,28 +1487,79 @@ pub trait WorkEscrow {
 
     #[view(getMilestone)]
     fn get_milestone(&self, agreement_id: u64, milestone_id: u64) -> OptionalValue<Milestone<Self::Api>> {
-        if self.milestones(agreement_id, milestone_id).is_empty() {
+        if self.milestones(agreement_id, milestone_id).is_empty() || self.is_legacy_agreement(agreement_id) {
             OptionalValue::None
         } else {
             OptionalValue::Some(self.milestones(agreement_id, milestone_id).get())
         }
     }
 
+    #[view(getMilestoneRevisions)]
+    fn get_milestone_revisions(
+        &self,
+        agreement_id: u64,
+        milestone_id: u64,
+    ) -> MultiValueEncoded<MilestoneRevision<Self::Api>> {
+        let mut out = MultiValueEncoded::new();
+        for revision in self.milestone_revisions(agreement_id, milestone_id).iter() {
+            out.push(revision);
+        }
+        out
+    }
+
+    #[view(getDispute)]
+    fn get_dispute(&self, agreement_id: u64, milestone_id: u64) -> OptionalValue<Dispute<Self::Api>> {
+        if self.disputes(agreement_id, milestone_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.disputes(agreement_id, milestone_id).get())
+        }
+    }
+
+    #[view(getArbitrators)]
+    fn get_arbitrators(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut out = MultiValueEncoded::new();
+        for arbitrator in self.arbitrators().iter() {
+            out.push(arbitrator);
+        }
+        out
+    }
+
+    #[view(getMutualTerminationProposal)]
+    fn get_mutual_termination_proposal(
+        &self,
+        agreement_id: u64,
+    ) -> OptionalValue<MutualTerminationProposal<Self::Api>> {
+        if self.mutual_termination_proposal(agreement_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.mutual_termination_proposal(agreement_id).get())
+        }
+    }
+
     #[view(getAgentReputation)]
     fn get_agent_reputation(&self, agent: ManagedAddress) -> ReputationSnapshot {
         self.load_reputation(&agent)
     }
 
     #[view(getProtocolStats)]
-    fn get_protocol_stats(&self) -> ProtocolStats<Self::Api> {
+    fn get_protocol_stats(
+        &self,
+        token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+    ) -> ProtocolStats<Self::Api> {
+        let token = match token {
+            OptionalValue::Some(value) => value,
+            OptionalValue::None => EgldOrEsdtTokenIdentifier::egld(),
+        };
+
         ProtocolStats {
             total_agreements: self.agreement_count().get(),
             active_agreements: self.active_agreement_count().get(),
             completed_agreements: self.completed_agreement_count().get(),
             terminated_agreements: self.terminated_agreement_count().get(),
-            total_gross_payouts: self.total_gross_payouts().get(),
-            total_protocol_fees: self.total_protocol_fees().get(),
-            total_revenue_deposited: self.total_revenue_deposited().get(),
+            total_gross_payouts: self.total_gross_payouts(&token).get(),
+            total_protocol_fees: self.total_protocol_fees(&token).get(),
+            total_revenue_deposited: self.total_revenue_deposited(&token).get(),
         }
     }
 
@@ -882,14 +1581,42 @@ pub trait WorkEscrow {
             termination_penalty_bps: self.termination_penalty_bps().get(),
             milestone_review_timeout_seconds: self.milestone_review_timeout_seconds().get(),
             max_milestones_per_agreement: self.max_milestones_per_agreement().get(),
+            max_revision_rounds: self.max_revision_rounds().get(),
+            funding_window_seconds: self.funding_window_seconds().get(),
+            worker_default_grace_seconds: self.worker_default_grace_seconds().get(),
+            max_notice_seconds: self.max_notice_seconds().get(),
+            termination_cancel_requires_consent: self.termination_cancel_requires_consent().get(),
             score_start: self.score_start().get(),
             paused: self.paused().get(),
         }
     }
 
     #[view(getClaimable)]
-    fn get_claimable(&self, agent: ManagedAddress) -> BigUint {
-        self.claimable(&agent).get()
+    fn get_claimable(&self, agent: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        let mut amount = self.claimable(&agent, &token).get();
+        if token.is_egld() {
+            amount += self.legacy_claimable(&agent).get();
+        }
+        amount
+    }
+
+    #[view(getAllowedTokens)]
+    fn get_allowed_tokens(&self) -> MultiValueEncoded<EgldOrEsdtTokenIdentifier> {
+        let mut out = MultiValueEncoded::new();
+        for token in self.allowed_tokens().iter() {
+            out.push(token);
+        }
+        out
+    }
+
+    #[view(isOfferTermsActivatable)]
+    fn is_offer_terms_activatable(&self, terms: OfferTerms<Self::Api>) -> bool {
+        self.offer_terms_error(&terms).is_none()
+    }
+
+    #[view(isOfferReleased)]
+    fn is_offer_released(&self, job_id: u64, offer_id: u64) -> bool {
+        self.offer_released(job_id, offer_id).get()
     }
 
     #[view(isOfferConsumed)]
@@ -907,9 +1634,101 @@ pub trait WorkEscrow {
 
     fn require_agreement(&self, agreement_id: u64) -> Agreement<Self::Api> {
         require!(!self.agreements(agreement_id).is_empty(), ERR_INVALID_STATE);
+        require!(!self.is_legacy_agreement(agreement_id), ERR_MIGRATION_PENDING);
         self.agreements(agreement_id).get()
     }
 
+    fn is_legacy_agreement(&self, agreement_id: u64) -> bool {
+        agreement_id > self.legacy_migration_cursor().get()
+            && agreement_id <= self.legacy_agreement_watermark().get()
+    }
+
+    fn migrate_legacy_agreement(&self, agreement_id: u64) {
+        if self.legacy_agreements(agreement_id).is_empty() {
+            return;
+        }
+        let legacy = self.legacy_agreements(agreement_id).get();
+        let now = self.blockchain().get_block_timestamp();
+
+        // Legacy agreements paid in EGLD and read notice and penalty from the globals.
+        let terms = AgreementTerms {
+            payment_token: EgldOrEsdtTokenIdentifier::egld(),
+            recurring: legacy.terms.recurring,
+            revenue_share: legacy.terms.revenue_share,
+            employer_bond_required: legacy.terms.employer_bond_required,
+            worker_bond_required: legacy.terms.worker_bond_required,
+            notice_seconds: self.default_notice_seconds().get(),
+            termination_penalty_bps: self.termination_penalty_bps().get(),
+            milestone_count: legacy.terms.milestone_count,
+        };
+        let funding_deadline_ts = if legacy.status == AgreementStatus::PendingFunding {
+            now + self.funding_window_seconds().get()
+        } else {
+            0
+        };
+
+        for milestone_id in 1..=terms.milestone_count {
+            if self.legacy_milestones(agreement_id, milestone_id).is_empty() {
+                continue;
+            }
+            let m = self.legacy_milestones(agreement_id, milestone_id).get();
+            self.milestones(agreement_id, milestone_id).set(Milestone {
+                id: m.id,
+                agreement_id: m.agreement_id,
+                amount: m.amount,
+                due_ts: m.due_ts,
+                review_timeout_seconds: m.review_timeout_seconds,
+                metadata_uri: m.metadata_uri,
+                state: m.state,
+                submitted_at: m.submitted_at,
+                review_deadline: m.review_deadline,
+                proof_uri: m.proof_uri,
+                reason_uri: m.reason_uri,
+                settlement_mode: m.settlement_mode,
+                paid_at: m.paid_at,
+                revision_count: 0,
+            });
+        }
+
+        self.agreements(agreement_id).set(Agreement {
+            id: legacy.id,
+            job_id: legacy.job_id,
+            offer_id: legacy.offer_id,
+            employer: legacy.employer,
+            worker: legacy.worker,
+            referrer: legacy.referrer,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            activated_at: legacy.activated_at,
+            notice_start_ts: legacy.notice_start_ts,
+            notice_end_ts: legacy.notice_end_ts,
+            requested_by_side: legacy.requested_by_side,
+            default_side: legacy.default_side,
+            terms,
+            funding_deadline_ts,
+        });
+    }
+
+    fn require_cancellable_termination(&self, agreement: &Agreement<Self::Api>) {
+        require!(
+            agreement.status == AgreementStatus::NoticePeriod && agreement.default_side == 0,
+            ERR_INVALID_STATE
+        );
+        require!(
+            self.blockchain().get_block_timestamp() < agreement.notice_end_ts,
+            ERR_INVALID_DEADLINE
+        );
+    }
+
+    fn require_mutual_termination_state(&self, agreement: &Agreement<Self::Api>) {
+        require!(
+            agreement.default_side == 0
+                && (agreement.status == AgreementStatus::Active
+                    || agreement.status == AgreementStatus::NoticePeriod),
+            ERR_INVALID_STATE
+        );
+    }
+
     fn require_milestone(&self, agreement_id: u64, milestone_id: u64) -> Milestone<Self::Api> {
         require!(
             !self.milestones(agreement_id, milestone_id).is_empty(),
@@ -918,12 +1737,16 @@ pub trait WorkEscrow {
         self.milestones(agreement_id, milestone_id).get()
     }
 
-    fn fetch_accepted_offer(&self, job_id: u64) -> AcceptedOfferSummary<Self::Api> {
+    fn fetch_accepted_offer(
+        &self,
+        job_id: u64,
+        application_id: u64,
+    ) -> AcceptedOfferSummary<Self::Api> {
         let accepted: OptionalValue<AcceptedOfferSummary<Self::Api>> = self
             .tx()
             .to(self.job_board().get())
             .typed(JobBoardProxy)
-            .get_accepted_offer(job_id)
+            .get_accepted_offer_for_application(job_id, application_id)
             .returns(ReturnsResult)
             .sync_call_readonly();
 
@@ -933,27 +1756,64 @@ pub trait WorkEscrow {
         }
     }
 
+    fn require_offer_unspent(&self, job_id: u64, offer_id: u64) {
+        require!(!self.offer_consumed(job_id, offer_id).get(), ERR_OFFER_CONSUMED);
+        // A released offer stays released even if the board has not voided it yet.
+        require!(!self.offer_released(job_id, offer_id).get(), ERR_OFFER_RELEASED);
+    }
+
+    fn notify_job_reopened(&self, job_id: u64, offer_id: u64) {
+        // Recorded first so the board can pull the reopen if this call fails.
+        self.offer_released(job_id, offer_id).set(true);
+        self.tx()
+            .to(self.job_board().get())
+            .gas(JOB_REOPEN_GAS)
+            .typed(JobBoardProxy)
+            .reopen_matched_job(job_id, offer_id)
+            .transfer_execute();
+    }
+
     fn validate_terms(&self, accepted: &AcceptedOfferSummary<Self::Api>) {
-        require!(
-            accepted.terms.revenue_share.profit_share_bps <= BPS_DENOMINATOR,
-            ERR_INVALID_BPS
-        );
-        require!(
-            accepted.terms.milestones.len() as u64 <= self.max_milestones_per_agreement().get(),
-            ERR_INVALID_AMOUNT
-        );
+        if let Some(err) = self.offer_terms_error(&accepted.terms) {
+            sc_panic!(err);
+        }
+    }
 
-        if accepted.terms.recurring.amount_per_period > 0u64 {
-            require!(accepted.terms.recurring.period_seconds > 0, ERR_INVALID_AMOUNT);
-            require!(accepted.terms.recurring.total_periods > 0, ERR_INVALID_AMOUNT);
-        } else {
-            require!(accepted.terms.recurring.total_periods == 0, ERR_INVALID_AMOUNT);
+    fn offer_terms_error(&self, terms: &OfferTerms<Self::Api>) -> Option<&'static str> {
+        if !self.allowed_tokens().contains(&terms.payment_token) {
+            return Some(ERR_INVALID_TOKEN);
+        }
+        if terms.revenue_share.profit_share_bps > BPS_DENOMINATOR {
+            return Some(ERR_INVALID_BPS);
+        }
+        if terms.milestones.len() as u64 > self.max_milestones_per_agreement().get() {
+            return Some(ERR_INVALID_AMOUNT);
+        }
+        // Global risk params bound what the parties may negotiate.
+        if terms.notice_seconds < self.default_notice_seconds().get()
+            || terms.notice_seconds > self.max_notice_seconds().get()
+        {
+            return Some(ERR_INVALID_DEADLINE);
+        }
+        if terms.termination_penalty_bps > self.termination_penalty_bps().get() {
+            return Some(ERR_INVALID_BPS);
         }
 
-        for m in accepted.terms.milestones.iter() {
-            require!(m.amount > 0u64, ERR_INVALID_AMOUNT);
-            require!(m.review_timeout_seconds > 0, ERR_INVALID_AMOUNT);
+        if terms.recurring.amount_per_period > 0u64 {
+            if terms.recurring.period_seconds == 0 || terms.recurring.total_periods == 0 {
+                return Some(ERR_INVALID_AMOUNT);
+            }
+        } else if terms.recurring.total_periods != 0 {
+            return Some(ERR_INVALID_AMOUNT);
         }
+
+        for m in terms.milestones.iter() {
+            if m.amount == 0u64 || m.review_timeout_seconds == 0 {
+                return Some(ERR_INVALID_AMOUNT);
+            }
+        }
+
+        None
     }
 
     fn try_activate(
@@ -1009,7 +1869,7 @@ pub trait WorkEscrow {
                 continue;
             }
             let milestone = self.milestones(agreement_id, milestone_id).get();
-            if milestone.state != MilestoneState::Paid {
+            if milestone.state != MilestoneState::Paid && milestone.state != MilestoneState::Cancelled {
                 return;
             }
         }
@@ -1018,15 +1878,18 @@ pub trait WorkEscrow {
         let worker_refund = funding.worker_bond_locked.clone();
 
         if employer_refund > 0u64 {
-            self.add_claimable(&agreement.employer, &employer_refund);
+            self.add_claimable(&agreement.employer, &agreement.terms.payment_token, &employer_refund);
         }
         if worker_refund > 0u64 {
-            self.add_claimable(&agreement.worker, &worker_refund);
+            self.add_claimable(&agreement.worker, &agreement.terms.payment_token, &worker_refund);
         }
 
         funding.employer_bond_locked = BigUint::zero();
         funding.worker_bond_locked = BigUint::zero();
 
+        // Every period and milestone is paid at this point, so nothing is owed.
+        let runway_refund = self.release_runway_to_employer(agreement, funding);
+
         agreement.status = AgreementStatus::Completed;
         self.agreement_financials(agreement_id).set(funding.clone());
         self.agreements(agreement_id).set(agreement.clone());
@@ -1050,9 +1913,214 @@ pub trait WorkEscrow {
             ReputationReason::Completion,
             agreement_id,
         );
+
+        self.agreement_completed_event(
+            agreement_id,
+            employer_refund,
+            worker_refund,
+            runway_refund,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn elapsed_recurring_periods(&self, agreement: &Agreement<Self::Api>, cutoff_ts: u64) -> u64 {
+        let recurring = &agreement.terms.recurring;
+        if recurring.next_pay_ts == 0
+            || recurring.period_seconds == 0
+            || recurring.paid_periods >= recurring.total_periods
+            || cutoff_ts < recurring.next_pay_ts
+        {
+            return 0;
+        }
+
+        let elapsed = (cutoff_ts - recurring.next_pay_ts) / recurring.period_seconds + 1;
+        core::cmp::min(elapsed, recurring.total_periods - recurring.paid_periods)
+    }
+
+    fn affordable_recurring_periods(
+        &self,
+        agreement: &Agreement<Self::Api>,
+        funding: &FundingState<Self::Api>,
+    ) -> u64 {
+        (&funding.runway_balance / &agreement.terms.recurring.amount_per_period)
+            .to_u64()
+            .unwrap_or(u64::MAX)
+    }
+
+    fn pay_recurring_periods(
+        &self,
+        agreement_id: u64,
+        agreement: &mut Agreement<Self::Api>,
+        funding: &mut FundingState<Self::Api>,
+        periods: u64,
+    ) {
+        let gross = &agreement.terms.recurring.amount_per_period * periods;
+        funding.runway_balance -= &gross;
+        agreement.terms.recurring.paid_periods += periods;
+        agreement.terms.recurring.next_pay_ts += agreement.terms.recurring.period_seconds * periods;
+
+        let (protocol_fee, _referral_fee, worker_net) =
+            self.credit_worker_payout(agreement, &gross, agreement_id);
+        self.record_agreement_totals(agreement_id, &gross, &protocol_fee);
+        self.apply_reputation_delta_times(
+            &agreement.employer,
+            SCORE_DELTA_RECURRING,
+            ReputationReason::OnTimeRecurringPayment,
+            agreement_id,
+            periods,
+        );
+
+        self.pay_claimed_event(
+            agreement_id,
+            agreement.terms.recurring.paid_periods,
+            periods,
+            gross,
+            protocol_fee,
+            worker_net,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn settle_notice_pay(
+        &self,
+        agreement_id: u64,
+        agreement: &mut Agreement<Self::Api>,
+        funding: &mut FundingState<Self::Api>,
+    ) {
+        if agreement.terms.recurring.total_periods == 0
+            || agreement.terms.recurring.amount_per_period == 0u64
+        {
+            return;
+        }
+
+        let cutoff_ts = agreement.notice_end_ts;
+        let periods = core::cmp::min(
+            self.elapsed_recurring_periods(agreement, cutoff_ts),
+            self.affordable_recurring_periods(agreement, funding),
+        );
+        if periods > 0 {
+            self.pay_recurring_periods(agreement_id, agreement, funding, periods);
+        }
+
+        let recurring = &agreement.terms.recurring;
+        if recurring.paid_periods >= recurring.total_periods {
+            return;
+        }
+
+        // Periods that elapsed but the runway cannot fully cover get what is left.
+        if recurring.next_pay_ts <= cutoff_ts {
+            let unpaid = self.elapsed_recurring_periods(agreement, cutoff_ts);
+            let owed = &recurring.amount_per_period * unpaid;
+            let gross = self.min_biguint(&owed, &funding.runway_balance);
+            let period_start = recurring.next_pay_ts - recurring.period_seconds;
+            self.settle_partial_pay(agreement_id, agreement, funding, &gross, period_start, cutoff_ts);
+            return;
+        }
+
+        let period_start = recurring.next_pay_ts - recurring.period_seconds;
+        if cutoff_ts <= period_start {
+            return;
+        }
+
+        let accrued = &recurring.amount_per_period * (cutoff_ts - period_start)
+            / recurring.period_seconds;
+        let gross = self.min_biguint(&accrued, &funding.runway_balance);
+        self.settle_partial_pay(agreement_id, agreement, funding, &gross, period_start, cutoff_ts);
+    }
+
+    fn settle_partial_pay(
+        &self,
+        agreement_id: u64,
+        agreement: &Agreement<Self::Api>,
+        funding: &mut FundingState<Self::Api>,
+        gross: &BigUint,
+        period_start: u64,
+        period_end: u64,
+    ) {
+        if *gross == 0u64 {
+            return;
+        }
+
+        funding.runway_balance -= gross;
+        let (protocol_fee, _referral_fee, worker_net) =
+            self.credit_worker_payout(agreement, gross, agreement_id);
+        self.record_agreement_totals(agreement_id, gross, &protocol_fee);
+
+        self.partial_pay_settled_event(
+            agreement_id,
+            period_start,
+            period_end,
+            gross.clone(),
+            protocol_fee,
+            worker_net,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn settle_pending_milestones(
+        &self,
+        agreement_id: u64,
+        agreement: &Agreement<Self::Api>,
+        funding: &mut FundingState<Self::Api>,
+    ) -> BigUint {
+        // Submitted work is paid as if the review window lapsed. Disputed amounts
+        // are returned as a hold the caller keeps out of the runway refund.
+        let mut disputed = BigUint::zero();
+        for milestone_id in 1..=agreement.terms.milestone_count {
+            if self.milestones(agreement_id, milestone_id).is_empty() {
+                continue;
+            }
+            let mut milestone = self.milestones(agreement_id, milestone_id).get();
+            if milestone.state == MilestoneState::Disputed {
+                disputed += &milestone.amount;
+                continue;
+            }
+            if milestone.state != MilestoneState::Submitted {
+                continue;
+            }
+
+            let gross = self.min_biguint(&milestone.amount, &funding.runway_balance);
+            if gross == 0u64 {
+                continue;
+            }
+
+            let now = self.blockchain().get_block_timestamp();
+            funding.runway_balance -= &gross;
+            milestone.state = MilestoneState::Paid;
+            milestone.settlement_mode = MilestoneSettlementMode::AutoApproved as u8;
+            milestone.paid_at = now;
+            self.milestones(agreement_id, milestone_id).set(milestone);
+
+            let (protocol_fee, _, worker_net) = self.credit_worker_payout(agreement, &gross, agreement_id);
+            self.record_agreement_totals(agreement_id, &gross, &protocol_fee);
+
+            self.milestone_settled_event(
+                agreement_id,
+                milestone_id,
+                MilestoneSettlementMode::AutoApproved as u8,
+                gross,
+                protocol_fee,
+                worker_net,
+                now,
+            );
+        }
+        self.min_biguint(&disputed, &funding.runway_balance)
+    }
+
+    fn release_runway_to_employer(
+        &self,
+        agreement: &Agreement<Self::Api>,
+        funding: &mut FundingState<Self::Api>,
+    ) -> BigUint {
+        let refund = core::mem::replace(&mut funding.runway_balance, BigUint::zero());
+        if refund > 0u64 {
+            self.add_claimable(&agreement.employer, &agreement.terms.payment_token, &refund);
+        }
+        refund
     }
 
     fn handle_employer_default(&self, agreement_id: u64, agreement: &mut Agreement<Self::Api>) {
+        let was_active = agreement.status == AgreementStatus::Active;
         agreement.default_side = EMPLOYER_SIDE;
         agreement.status = AgreementStatus::NoticePeriod;
         agreement.notice_start_ts = self.blockchain().get_block_timestamp();
@@ -1060,6 +2128,37 @@ pub trait WorkEscrow {
         agreement.requested_by_side = WORKER_SIDE;
         self.agreements(agreement_id).set(agreement.clone());
 
+        // Notice periods already left the active count when they started.
+        if was_active {
+            self.active_agreement_count().update(|v| {
+                if *v > 0 {
+                    *v -= 1;
+                }
+            });
+        }
+
+        self.apply_reputation_delta(
+            &agreement.employer,
+            SCORE_DELTA_EMPLOYER_DEFAULT,
+            ReputationReason::EmployerDefault,
+            agreement_id,
+        );
+
+        self.agreement_defaulted_event(
+            agreement_id,
+            EMPLOYER_SIDE,
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn handle_worker_default(&self, agreement_id: u64, agreement: &mut Agreement<Self::Api>) {
+        agreement.default_side = WORKER_SIDE;
+        agreement.status = AgreementStatus::NoticePeriod;
+        agreement.notice_start_ts = self.blockchain().get_block_timestamp();
+        agreement.notice_end_ts = agreement.notice_start_ts;
+        agreement.requested_by_side = EMPLOYER_SIDE;
+        self.agreements(agreement_id).set(agreement.clone());
+
         self.active_agreement_count().update(|v| {
             if *v > 0 {
                 *v -= 1;
@@ -1067,15 +2166,15 @@ pub trait WorkEscrow {
         });
 
         self.apply_reputation_delta(
-            &agreement.employer,
-            SCORE_DELTA_EMPLOYER_DEFAULT,
-            ReputationReason::EmployerDefault,
+            &agreement.worker,
+            SCORE_DELTA_WORKER_DEFAULT,
+            ReputationReason::WorkerDefault,
             agreement_id,
         );
 
         self.agreement_defaulted_event(
             agreement_id,
-            EMPLOYER_SIDE,
+            WORKER_SIDE,
             self.blockchain().get_block_timestamp(),
         );
     }
@@ -1086,6 +2185,7 @@ pub trait WorkEscrow {
         gross: &BigUint,
         agreement_id: u64,
     ) -> (BigUint, BigUint, BigUint) {
+        let token = &agreement.terms.payment_token;
         let protocol_fee = self.mul_bps(gross, agreement.terms.revenue_share.protocol_fee_bps_snapshot);
         let worker_net = gross - &protocol_fee;
 
@@ -1095,15 +2195,15 @@ pub trait WorkEscrow {
                 &protocol_fee,
                 agreement.terms.revenue_share.referral_share_bps_snapshot,
             );
-            self.add_claimable(&agreement.referrer, &referral_fee);
+            self.add_claimable(&agreement.referrer, token, &referral_fee);
         }
         let treasury_fee = &protocol_fee - &referral_fee;
 
-        self.add_claimable(&agreement.worker, &worker_net);
-        self.add_claimable(&self.treasury().get(), &treasury_fee);
+        self.add_claimable(&agreement.worker, token, &worker_net);
+        self.add_claimable(&self.treasury().get(), token, &treasury_fee);
 
-        self.total_protocol_fees().update(|v| *v += &protocol_fee);
-        self.total_gross_payouts().update(|v| *v += gross);
+        self.total_protocol_fees(token).update(|v| *v += &protocol_fee);
+        self.total_gross_payouts(token).update(|v| *v += gross);
 
         self.revenue_ledger(agreement_id).update(|v| {
             *v += 1u64;
@@ -1144,20 +2244,31 @@ pub trait WorkEscrow {
         delta: i64,
         reason: ReputationReason,
         agreement_id: u64,
+    ) {
+        self.apply_reputation_delta_times(agent, delta, reason, agreement_id, 1);
+    }
+
+    fn apply_reputation_delta_times(
+        &self,
+        agent: &ManagedAddress,
+        delta: i64,
+        reason: ReputationReason,
+        agreement_id: u64,
+        times: u64,
     ) {
         let mut rep = self.load_reputation(agent);
         let prev = rep.score;
 
         if delta >= 0 {
-            let up = delta as u64;
+            let up = (delta as u64).saturating_mul(times);
             rep.score = core::cmp::min(SCORE_MAX, rep.score.saturating_add(up));
         } else {
-            let down = (-delta) as u64;
+            let down = ((-delta) as u64).saturating_mul(times);
             rep.score = rep.score.saturating_sub(down);
         }
 
         match reason {
-            ReputationReason::OnTimeRecurringPayment => rep.on_time_recurring_payments += 1,
+            ReputationReason::OnTimeRecurringPayment => rep.on_time_recurring_payments += times,
             ReputationReason::WorkerMilestoneSettled => rep.milestones_settled += 1,
             ReputationReason::EmployerDefault => rep.defaults_as_employer += 1,
             ReputationReason::WorkerDefault => rep.defaults_as_worker += 1,
@@ -1197,11 +2308,26 @@ pub trait WorkEscrow {
         }
     }
 
-    fn add_claimable(&self, account: &ManagedAddress, amount: &BigUint) {
+    fn add_claimable(
+        &self,
+        account: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
         if amount == &BigUint::zero() {
             return;
         }
-        self.claimable(account).update(|v| *v += amount);
+        self.claimable(account, token).update(|v| *v += amount);
+    }
+
+    fn require_agreement_payment(&self, agreement: &Agreement<Self::Api>) -> BigUint {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == agreement.terms.payment_token && payment.token_nonce == 0,
+            ERR_INVALID_TOKEN
+        );
+        require!(payment.amount > 0u64, ERR_INVALID_AMOUNT);
+        payment.amount
     }
 
     fn record_agreement_totals(&self, agreement_id: u64, gross: &BigUint, fees: &BigUint) {
@@ -1292,6 +2418,19 @@ pub trait WorkEscrow {
         &self,
         #[indexed] agreement_id: u64,
         #[indexed] period_index: u64,
+        #[indexed] periods_paid: u64,
+        #[indexed] gross: BigUint,
+        #[indexed] fee: BigUint,
+        #[indexed] worker_net: BigUint,
+        timestamp: u64,
+    );
+
+    #[event("partialPaySettled")]
+    fn partial_pay_settled_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] period_start_ts: u64,
+        #[indexed] period_end_ts: u64,
         #[indexed] gross: BigUint,
         #[indexed] fee: BigUint,
         #[indexed] worker_net: BigUint,
@@ -1307,6 +2446,16 @@ pub trait WorkEscrow {
         timestamp: u64,
     );
 
+    #[event("milestoneCancelled")]
+    fn milestone_cancelled_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] milestone_id: u64,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] amount: BigUint,
+        timestamp: u64,
+    );
+
     #[event("milestoneRejected")]
     fn milestone_rejected_event(
         &self,
@@ -1316,6 +2465,16 @@ pub trait WorkEscrow {
         timestamp: u64,
     );
 
+    #[event("milestoneResubmitted")]
+    fn milestone_resubmitted_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] milestone_id: u64,
+        #[indexed] worker: &ManagedAddress,
+        #[indexed] revision_round: u64,
+        timestamp: u64,
+    );
+
     #[event("milestoneSettled")]
     fn milestone_settled_event(
         &self,
@@ -1328,6 +2487,27 @@ pub trait WorkEscrow {
         timestamp: u64,
     );
 
+    #[event("disputeRaised")]
+    fn dispute_raised_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] milestone_id: u64,
+        #[indexed] worker: &ManagedAddress,
+        timestamp: u64,
+    );
+
+    #[event("disputeResolved")]
+    fn dispute_resolved_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] milestone_id: u64,
+        #[indexed] arbitrator: &ManagedAddress,
+        #[indexed] worker_share_bps: u64,
+        #[indexed] worker_gross: BigUint,
+        #[indexed] employer_share: BigUint,
+        timestamp: u64,
+    );
+
     #[event("revenueDeposited")]
     fn revenue_deposited_event(
         &self,
@@ -1349,6 +2529,25 @@ pub trait WorkEscrow {
         timestamp: u64,
     );
 
+    #[event("terminationCancelled")]
+    fn termination_cancelled_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] side: u8,
+        timestamp: u64,
+    );
+
+    #[event("mutualTerminationProposed")]
+    fn mutual_termination_proposed_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] proposer_side: u8,
+        #[indexed] end_ts: u64,
+        #[indexed] confirm_deadline_ts: u64,
+        #[indexed] worker_settlement: BigUint,
+        timestamp: u64,
+    );
+
     #[event("agreementTerminated")]
     fn agreement_terminated_event(
         &self,
@@ -1357,6 +2556,44 @@ pub trait WorkEscrow {
         #[indexed] penalty: BigUint,
         #[indexed] employer_refund: BigUint,
         #[indexed] worker_refund: BigUint,
+        #[indexed] runway_refund: BigUint,
+        timestamp: u64,
+    );
+
+    #[event("agreementCompleted")]
+    fn agreement_completed_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] employer_refund: BigUint,
+        #[indexed] worker_refund: BigUint,
+        #[indexed] runway_refund: BigUint,
+        timestamp: u64,
+    );
+
+    #[event("acceptedOfferReleased")]
+    fn accepted_offer_released_event(
+        &self,
+        #[indexed] job_id: u64,
+        #[indexed] offer_id: u64,
+        #[indexed] caller: &ManagedAddress,
+        timestamp: u64,
+    );
+
+    #[event("legacyAgreementsMigrated")]
+    fn legacy_agreements_migrated_event(
+        &self,
+        #[indexed] from_agreement_id: u64,
+        #[indexed] to_agreement_id: u64,
+        timestamp: u64,
+    );
+
+    #[event("agreementCancelled")]
+    fn agreement_cancelled_event(
+        &self,
+        #[indexed] agreement_id: u64,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] employer_refund: BigUint,
+        #[indexed] worker_refund: BigUint,
         timestamp: u64,
     );
 
@@ -1375,6 +2612,7 @@ pub trait WorkEscrow {
     fn fee_withdrawn_event(
         &self,
         #[indexed] account: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: BigUint,
         timestamp: u64,
     );
@@ -1435,6 +2673,21 @@ pub trait WorkEscrow {
     #[storage_mapper("maxMilestonesPerAgreement")]
     fn max_milestones_per_agreement(&self) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("maxRevisionRounds")]
+    fn max_revision_rounds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("fundingWindowSeconds")]
+    fn funding_window_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("workerDefaultGraceSeconds")]
+    fn worker_default_grace_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("maxNoticeSeconds")]
+    fn max_notice_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("terminationCancelRequiresConsent")]
+    fn termination_cancel_requires_consent(&self) -> SingleValueMapper<bool>;
+
     #[storage_mapper("scoreStart")]
     fn score_start(&self) -> SingleValueMapper<u64>;
 
@@ -1453,14 +2706,65 @@ pub trait WorkEscrow {
     #[storage_mapper("agreements")]
     fn agreements(&self, agreement_id: u64) -> SingleValueMapper<Agreement<Self::Api>>;
 
+    #[storage_mapper("agreements")]
+    fn legacy_agreements(&self, agreement_id: u64) -> SingleValueMapper<LegacyAgreement<Self::Api>>;
+
+    #[storage_mapper("milestones")]
+    fn legacy_milestones(
+        &self,
+        agreement_id: u64,
+        milestone_id: u64,
+    ) -> SingleValueMapper<LegacyMilestone<Self::Api>>;
+
+    #[storage_mapper("storageLayoutVersion")]
+    fn storage_layout_version(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyAgreementWatermark")]
+    fn legacy_agreement_watermark(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyMigrationCursor")]
+    fn legacy_migration_cursor(&self) -> SingleValueMapper<u64>;
+
     #[storage_mapper("agreementFinancials")]
     fn agreement_financials(&self, agreement_id: u64) -> SingleValueMapper<FundingState<Self::Api>>;
 
     #[storage_mapper("milestones")]
     fn milestones(&self, agreement_id: u64, milestone_id: u64) -> SingleValueMapper<Milestone<Self::Api>>;
 
+    #[storage_mapper("milestoneRevisions")]
+    fn milestone_revisions(
+        &self,
+        agreement_id: u64,
+        milestone_id: u64,
+    ) -> VecMapper<MilestoneRevision<Self::Api>>;
+
+    #[storage_mapper("disputes")]
+    fn disputes(&self, agreement_id: u64, milestone_id: u64) -> SingleValueMapper<Dispute<Self::Api>>;
+
+    #[storage_mapper("arbitrators")]
+    fn arbitrators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("terminationCancelConsent")]
+    fn termination_cancel_consent(&self, agreement_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("mutualTerminationProposal")]
+    fn mutual_termination_proposal(
+        &self,
+        agreement_id: u64,
+    ) -> SingleValueMapper<MutualTerminationProposal<Self::Api>>;
+
     #[storage_mapper("claimable")]
-    fn claimable(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    fn claimable(
+        &self,
+        account: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("allowedTokens")]
+    fn allowed_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("offerReleased")]
+    fn offer_released(&self, job_id: u64, offer_id: u64) -> SingleValueMapper<bool>;
 
     #[storage_mapper("offerConsumed")]
     fn offer_consumed(&self, job_id: u64, offer_id: u64) -> SingleValueMapper<bool>;
@@ -1472,13 +2776,26 @@ pub trait WorkEscrow {
     fn reputation(&self, agent: &ManagedAddress) -> SingleValueMapper<ReputationSnapshot>;
 
     #[storage_mapper("totalGrossPayouts")]
-    fn total_gross_payouts(&self) -> SingleValueMapper<BigUint>;
+    fn total_gross_payouts(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalProtocolFees")]
+    fn total_protocol_fees(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalRevenueDeposited")]
+    fn total_revenue_deposited(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Pre-ESDT layouts keyed only by account; every balance under them is EGLD.
+    #[storage_mapper("claimable")]
+    fn legacy_claimable(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalGrossPayouts")]
+    fn legacy_total_gross_payouts(&self) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("totalProtocolFees")]
-    fn total_protocol_fees(&self) -> SingleValueMapper<BigUint>;
+    fn legacy_total_protocol_fees(&self) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("totalRevenueDeposited")]
-    fn total_revenue_deposited(&self) -> SingleValueMapper<BigUint>;
+    fn legacy_total_revenue_deposited(&self) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("agreementTotalGrossPaid")]
     fn agreement_total_gross_paid(&self, agreement_id: u64) -> SingleValueMapper<BigUint>;
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           28
+// Endpoints:                           56
 // Async Callback (empty):               1
-// Total number of exported functions:  31
+// Total number of exported functions:  59
 
 #![no_std]
 
@@ -24,29 +24,57 @@ multiversx_sc_wasm_adapter::endpoints! {
         fundEmployerRunway => fund_employer_runway
         fundWorkerBond => fund_worker_bond
         topUpRunway => top_up_runway
+        releaseUnactivatableOffer => release_unactivatable_offer
+        cancelUnfundedAgreement => cancel_unfunded_agreement
         claimRecurringPay => claim_recurring_pay
         submitMilestone => submit_milestone
         approveMilestone => approve_milestone
         rejectMilestone => reject_milestone
+        resubmitMilestone => resubmit_milestone
         autoApproveMilestone => auto_approve_milestone
+        raiseDispute => raise_dispute
+        resolveDispute => resolve_dispute
+        cancelMilestone => cancel_milestone
+        flagWorkerDefault => flag_worker_default
         depositRevenue => deposit_revenue
         requestTerminate => request_terminate
+        consentTerminationCancel => consent_termination_cancel
+        cancelTermination => cancel_termination
         finalizeTerminate => finalize_terminate
+        proposeMutualTermination => propose_mutual_termination
+        confirmMutualTermination => confirm_mutual_termination
         withdrawClaimable => withdraw_claimable
         setProtocolFeeBps => set_protocol_fee_bps
         setReferralShareBps => set_referral_share_bps
         setTreasury => set_treasury
         setMinUptimeScore => set_min_uptime_score
         setRiskParams => set_risk_params
+        setMaxRevisionRounds => set_max_revision_rounds
+        setFundingWindowSeconds => set_funding_window_seconds
+        setWorkerDefaultGraceSeconds => set_worker_default_grace_seconds
+        setMaxNoticeSeconds => set_max_notice_seconds
+        setTerminationCancelRequiresConsent => set_termination_cancel_requires_consent
         setPaused => set_paused
         setOwner => set_owner
+        migrateLegacyAgreements => migrate_legacy_agreements
+        addArbitrator => add_arbitrator
+        removeArbitrator => remove_arbitrator
+        addAllowedToken => add_allowed_token
+        removeAllowedToken => remove_allowed_token
         getAgreement => get_agreement
         getAgreementFinancials => get_agreement_financials
         getMilestone => get_milestone
+        getMilestoneRevisions => get_milestone_revisions
+        getDispute => get_dispute
+        getArbitrators => get_arbitrators
+        getMutualTerminationProposal => get_mutual_termination_proposal
         getAgentReputation => get_agent_reputation
         getProtocolStats => get_protocol_stats
         getConfig => get_config
         getClaimable => get_claimable
+        getAllowedTokens => get_allowed_tokens
+        isOfferTermsActivatable => is_offer_terms_activatable
+        isOfferReleased => is_offer_released
         isOfferConsumed => is_offer_consumed
     )
 }